@@ -0,0 +1,34 @@
+use yew::{
+    function_component,
+    html,
+    Classes,
+    Html,
+    Properties,
+};
+
+#[derive(Clone, PartialEq, Properties)]
+pub(crate) struct ArrowPathProps {
+    #[prop_or_default]
+    pub(crate) class: Classes,
+}
+
+#[function_component(ArrowPath)]
+pub(crate) fn arrow_path(props: &ArrowPathProps) -> Html {
+    html! {
+        <svg
+            aria-hidden="true"
+            class={props.class.clone()}
+            xmlns="http://www.w3.org/2000/svg"
+            fill="none"
+            viewBox="0 0 24 24"
+            stroke-width="1.5"
+            stroke="currentColor"
+        >
+            <path
+                stroke-linecap="round"
+                stroke-linejoin="round"
+                d="M16.023 9.348h4.992v-.001M2.985 19.644v-4.992m0 0h4.992m-4.993 0l3.181 3.183a8.25 8.25 0 0013.803-3.7M4.031 9.865a8.25 8.25 0 0113.803-3.7l3.181 3.182m0-4.991v4.99"
+            />
+        </svg>
+    }
+}
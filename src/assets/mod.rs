@@ -1,8 +1,10 @@
+mod arrow_path;
 mod check_badge;
 mod exclamation_triangle;
 mod question_mark_circle;
 mod x_mark;
 
+pub(crate) use arrow_path::ArrowPath;
 pub(crate) use check_badge::CheckBadge;
 pub(crate) use exclamation_triangle::ExclamationTriangle;
 pub(crate) use question_mark_circle::QuestionMarkCircle;
@@ -1,3 +1,5 @@
+use std::fmt::Write;
+
 use wasm_bindgen::JsCast;
 
 macro_rules! console_error {
@@ -7,6 +9,7 @@ macro_rules! console_error {
 }
 pub(crate) use console_error;
 
+#[allow(clippy::upper_case_acronyms)]
 pub(crate) struct DOM;
 
 impl DOM {
@@ -49,6 +52,105 @@ impl DOM {
     pub(crate) fn local_storage() -> Option<web_sys::Storage> {
         DOM::window()?.local_storage().ok()?
     }
+
+    /// `content` of the `<meta name="{name}">` tag of the document.
+    pub(crate) fn meta_content(name: &str) -> Option<String> {
+        DOM::document()?
+            .query_selector(&format!("meta[name=\"{name}\"]"))
+            .ok()??
+            .get_attribute("content")
+    }
+
+    /// Device pixels per CSS pixel, 1 when unknown.
+    pub(crate) fn device_pixel_ratio() -> f64 {
+        DOM::window().map_or(1.0, |window| window.device_pixel_ratio())
+    }
+
+    /// Size of the layout viewport, in CSS pixels.
+    pub(crate) fn viewport_size() -> Option<(f64, f64)> {
+        let window = DOM::window()?;
+        let width = window.inner_width().ok()?.as_f64()?;
+        let height = window.inner_height().ok()?.as_f64()?;
+
+        Some((width, height))
+    }
+
+    /// Whether the CSS media query matches, `false` when it is unsupported.
+    pub(crate) fn matches_media(query: &str) -> bool {
+        DOM::window()
+            .and_then(|window| window.match_media(query).ok()?)
+            .is_some_and(|media_query_list| media_query_list.matches())
+    }
+
+    /// Size on screen, in CSS pixels, of the picture of the `<img>` with
+    /// the id `id`, fitted in its layout box as `object-fit: contain` does;
+    /// `None` until it is loaded or while it is not laid out. CSS
+    /// transforms, e.g. a zoom, are left out.
+    pub(crate) fn rendered_image_size(id: &str) -> Option<(f64, f64)> {
+        let image = DOM::get_element_by_id(id)?
+            .dyn_into::<web_sys::HtmlImageElement>()
+            .ok()?;
+        let natural_width = f64::from(image.natural_width());
+        let natural_height = f64::from(image.natural_height());
+        let width = f64::from(image.client_width());
+        let height = f64::from(image.client_height());
+        if natural_width <= 0.0 || natural_height <= 0.0 || width <= 0.0 {
+            return None;
+        }
+        // the element can be larger than the picture, e.g. a square frame
+        // around a wide image
+        let scale = (width / natural_width).min(height / natural_height);
+
+        Some((natural_width * scale, natural_height * scale))
+    }
+
+    /// Milliseconds on the page's high-resolution clock
+    /// (`performance.now()`).
+    pub(crate) fn now() -> Option<f64> {
+        Some(DOM::window()?.performance()?.now())
+    }
+
+    /// Random (version 4) UUID. `crypto.randomUUID` only exists in secure
+    /// contexts, so elsewhere (e.g. on plain http) it is made from
+    /// `crypto.getRandomValues`, or `Math.random` as a last resort.
+    pub(crate) fn random_uuid() -> String {
+        let crypto = DOM::window().and_then(|window| window.crypto().ok());
+        if let Some(crypto) = &crypto {
+            if js_sys::Reflect::has(crypto, &"randomUUID".into()) == Ok(true) {
+                return crypto.random_uuid();
+            }
+        }
+        let mut bytes = [0_u8; 16];
+        let filled = crypto.is_some_and(|crypto| {
+            crypto.get_random_values_with_u8_array(&mut bytes).is_ok()
+        });
+        if !filled {
+            for byte in &mut bytes {
+                *byte = (js_sys::Math::random() * 256.0) as u8;
+            }
+        }
+
+        uuid_v4(bytes)
+    }
+}
+
+/// Formats random bytes as a version 4 UUID.
+fn uuid_v4(mut bytes: [u8; 16]) -> String {
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex = bytes.iter().fold(String::new(), |mut hex, byte| {
+        let _ = write!(hex, "{byte:02x}");
+        hex
+    });
+
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
 }
 
 #[cfg(test)]
@@ -120,6 +222,7 @@ impl DOM {
         }
     }
 
+    #[allow(clippy::op_ref)]
     pub(crate) fn has_button_with_inner_html(inner_html: &str) -> bool {
         let buttons = DOM::document()
             .expect("document to be rendered")
@@ -141,3 +244,28 @@ impl DOM {
         found
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+
+    use super::{
+        uuid_v4,
+        DOM,
+    };
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    fn uuid_is_formatted_as_version_4() {
+        assert_eq!(uuid_v4([0xff; 16]), "ffffffff-ffff-4fff-bfff-ffffffffffff");
+        assert_eq!(uuid_v4([0; 16]), "00000000-0000-4000-8000-000000000000");
+    }
+
+    #[wasm_bindgen_test]
+    fn random_uuids_differ() {
+        assert_ne!(DOM::random_uuid(), DOM::random_uuid());
+    }
+}
@@ -38,10 +38,16 @@ use yew_router::{
 
 use crate::{
     dom::DOM,
+    request::{
+        Api,
+        ApiConfig,
+        GlooClient,
+    },
     routes::{
         switch,
         Route,
     },
+    shared_components::Loading,
 };
 
 static MARKDOWN_DIR: Dir = include_dir!("$CARGO_MANIFEST_DIR/src/markdown");
@@ -51,7 +57,7 @@ lazy_static! {
         MARKDOWN_DIR.dirs().map(|d| d.path()).collect();
 }
 
-pub(crate) static DEFAULT_PAGE: &'static str = std::env!("DEFAULT_PAGE");
+pub(crate) static DEFAULT_PAGE: &str = std::env!("DEFAULT_PAGE");
 
 pub(crate) static DEFAULT_LANGUAGE: AtomicUsize = AtomicUsize::new(0);
 
@@ -95,7 +101,9 @@ impl Reducible for Language {
 
 #[function_component(App)]
 pub fn app() -> Html {
-    let language = use_reducer_eq(|| Language::default());
+    let language = use_reducer_eq(Language::default);
+    // requests wait for the configuration, so they go to the right hosts
+    let api = use_state_eq(|| None::<Api>);
     let page_loaded = use_state_eq(|| true);
 
     {
@@ -116,6 +124,16 @@ pub fn app() -> Html {
         });
     }
 
+    {
+        let api = api.clone();
+        use_effect_with((), move |_| {
+            wasm_bindgen_futures::spawn_local(async move {
+                let config = ApiConfig::load().await;
+                api.set(Some(GlooClient::new(config).into()));
+            });
+        });
+    }
+
     html! {
         <BrowserRouter>
             <ContextProvider<UseReducerHandle<Language>> context={language}>
@@ -127,7 +145,13 @@ pub fn app() -> Html {
                         "bg-stone-400",
                     ]}
                 >
-                    <Switch<Route> render={switch} />
+                    if let Some(api) = (*api).clone() {
+                        <ContextProvider<Api> context={api}>
+                            <Switch<Route> render={switch} />
+                        </ContextProvider<Api>>
+                    } else {
+                        <Loading />
+                    }
                 </section>
             </ContextProvider<UseReducerHandle<Language>>>
         </BrowserRouter>
@@ -139,6 +163,13 @@ pub(crate) use helpers_for_tests::*;
 
 #[cfg(test)]
 mod helpers_for_tests {
+    /// 2x1 black PNG, for tests needing a loaded image.
+    pub(crate) const WIDE_IMAGE: &str = "data:image/png;base64,\
+                                         iVBORw0KGgoAAAANSUhEUgAAAAIAAA\
+                                         ABCAIAAAB7QOjdAAAAC0lEQVR4nGNg\
+                                         AAMAAAcAAbKGrPQAAAAASUVORK5CYI\
+                                         I=";
+
     pub(crate) fn markdown_to_decoded_html(text: &str) -> String {
         let html = markdown::to_html(text);
         // insert target="_blank" on links
@@ -152,11 +183,54 @@ mod helpers_for_tests {
         yew::platform::time::sleep(duration).await;
     }
 
+    /// Clicks the button with the id, which must be rendered.
+    pub(crate) fn click_button(id: &str) {
+        use wasm_bindgen::JsCast;
+
+        crate::dom::DOM::get_button_by_id(id)
+            .unwrap_or_else(|| panic!("Element #{id} to be present"))
+            .dyn_into::<web_sys::HtmlElement>()
+            .expect("Element to be castable to HtmlElement")
+            .click();
+    }
+
+    #[derive(yew::Properties, PartialEq)]
+    pub(crate) struct WithApiProps {
+        pub(crate) api: crate::request::Api,
+    }
+
+    /// Renders `C` with `api` as the [`crate::request::Api`] context.
+    #[yew::function_component(WithApi)]
+    pub(crate) fn with_api<C>(props: &WithApiProps) -> yew::Html
+    where
+        C: yew::BaseComponent,
+        C::Properties: Default,
+    {
+        yew::html! {
+            <yew::ContextProvider<crate::request::Api>
+                context={props.api.clone()}
+            >
+                <C ..C::Properties::default() />
+            </yew::ContextProvider<crate::request::Api>>
+        }
+    }
+
+    /// Renders the component into `#output`, answering its requests with
+    /// the given [`crate::request::FakeClient`] (a default one if omitted).
     macro_rules! render_yew_component {
         ($component:ident) => {
-            yew::Renderer::<$component>::with_root(
+            crate::render_yew_component!(
+                $component,
+                crate::request::FakeClient::default()
+            )
+        };
+        ($component:ident, $api:expr) => {
+            yew::Renderer::<crate::WithApi<$component>>::with_root_and_props(
                 crate::dom::DOM::get_element_by_id("output")
                     .expect("element with id #output to be present"),
+                crate::WithApiProps {
+                    api: crate::request::Api::from($api),
+                },
             )
             .render();
         };
@@ -0,0 +1,412 @@
+mod trial;
+
+use yew::{
+    classes,
+    function_component,
+    html,
+    use_context,
+    use_effect_with,
+    use_state_eq,
+    Callback,
+    Html,
+    UseReducerHandle,
+};
+
+use self::trial::{
+    AbxStimulus,
+    AbxTrial,
+};
+use crate::{
+    pages::{
+        experiment::{
+            Experiment,
+            ExperimentPage,
+            ExposureMask,
+            StimuliProps,
+        },
+        markdown_to_yew_html,
+    },
+    shared_components::{
+        Button,
+        Loading,
+    },
+    Language,
+};
+
+/// ABX discrimination: A, B and X (one of them) are revealed one at a time
+/// in random order, and the participant tells which one X matches.
+pub(crate) struct Abx;
+
+impl Experiment for Abx {
+    type Stimuli = AbxStimuli;
+
+    const PROMPT: &'static str = "abx_prompt";
+}
+
+pub(crate) type ExperimentAbx = ExperimentPage<Abx>;
+
+#[function_component(AbxStimuli)]
+pub(crate) fn abx_stimuli(props: &StimuliProps) -> Html {
+    let language = match use_context::<UseReducerHandle<Language>>() {
+        Some(ctx) => (*ctx).clone(),
+        None => Language::default(),
+    };
+    let trial = use_state_eq(|| None::<AbxTrial>);
+    // how many stimuli of the reveal order have been shown
+    let revealed = use_state_eq(|| 1_usize);
+    let shown = use_state_eq(|| None::<AbxStimulus>);
+
+    {
+        let trial = trial.clone();
+        let revealed = revealed.clone();
+        let shown = shown.clone();
+        use_effect_with(
+            (props.loading, props.images.clone()),
+            move |(loading, images)| {
+                let new_trial = match loading {
+                    true => None,
+                    false => AbxTrial::new(images, js_sys::Math::random),
+                };
+                revealed.set(1);
+                shown.set(
+                    new_trial.as_ref().map(|trial| trial.reveal_order()[0]),
+                );
+                trial.set(new_trial);
+            },
+        );
+    }
+
+    let all_revealed = trial
+        .as_ref()
+        .is_some_and(|trial| *revealed >= trial.reveal_order().len());
+
+    let reveal_next = {
+        let trial = trial.clone();
+        let revealed = revealed.clone();
+        let shown = shown.clone();
+        Callback::from(move |_| {
+            let Some(next) = trial
+                .as_ref()
+                .and_then(|trial| trial.reveal_order().get(*revealed))
+            else {
+                return;
+            };
+            shown.set(Some(*next));
+            revealed.set(*revealed + 1);
+        })
+    };
+
+    let next_button = language.load_file("abx_next_button.md");
+    let next_button = markdown_to_yew_html(next_button.unwrap_or(""));
+
+    // stimuli already revealed can be shown again
+    let stimulus_buttons = trial
+        .as_ref()
+        .map(|trial| &trial.reveal_order()[..*revealed])
+        .unwrap_or_default()
+        .iter()
+        .map(|stimulus| {
+            let onclick = {
+                let shown = shown.clone();
+                let stimulus = *stimulus;
+                Callback::from(move |_| shown.set(Some(stimulus)))
+            };
+
+            html! {
+                <Button
+                    id={format!("abx_stimulus_button_{}", stimulus.label())}
+                    class={classes![
+                        "border-2",
+                        "border-gray-800",
+                        "hover:text-gray-200",
+                        (*shown == Some(*stimulus)).then_some("bg-black/[0.2]"),
+                    ]}
+                    onclick={onclick}
+                >
+                    { stimulus.label() }
+                </Button>
+            }
+        })
+        .collect::<Html>();
+
+    let answer_buttons = [AbxStimulus::A, AbxStimulus::B]
+        .into_iter()
+        .map(|answer| {
+            let text = language.load_file("abx_answer_button.md");
+            let text = text.unwrap_or("").replace("{label}", answer.label());
+            let text = markdown_to_yew_html(&text);
+            let onclick = {
+                let trial = trial.clone();
+                let onvote = props.onvote.clone();
+                Callback::from(move |_| {
+                    if let Some(trial) = trial.as_ref() {
+                        onvote.emit(trial.vote(answer));
+                    }
+                })
+            };
+
+            html! {
+                <Button
+                    id={format!("abx_answer_button_{}", answer.label())}
+                    class={classes![
+                        "border-2",
+                        "border-gray-800",
+                        "hover:text-gray-200",
+                        "w-full",
+                    ]}
+                    disabled={!all_revealed || props.answer_disabled()}
+                    onclick={onclick}
+                >
+                    { text }
+                </Button>
+            }
+        })
+        .collect::<Html>();
+
+    let shown_image = trial.as_ref().zip(*shown);
+
+    html! {
+        <>
+            <section
+                id="images_list"
+                class={classes![
+                    "relative",
+                    "flex-1",
+                    "overflow-hidden",
+                    "flex",
+                    "flex-col",
+                    "items-center",
+                    "justify-center",
+                ]}
+            >
+                <div
+                    class={classes![
+                        "relative",
+                        "h-5/6",
+                        "aspect-square",
+                        "w-fit",
+                    ]}
+                >
+                    if let Some((trial, stimulus)) = shown_image {
+                        <img
+                            id="abx_image"
+                            class={classes!["h-full"]}
+                            src={trial.image(stimulus).to_string()}
+                            alt=""
+                        />
+                        <span
+                            id="abx_label"
+                            class={classes![
+                                "absolute",
+                                "top-4",
+                                "left-4",
+                                "w-10",
+                                "h-10",
+                                "rounded-full",
+                                "bg-gray-800",
+                                "text-gray-100",
+                                "flex",
+                                "items-center",
+                                "justify-center",
+                            ]}
+                        >
+                            { stimulus.label() }
+                        </span>
+                    } else {
+                        <div id="loading_status_button_0">
+                            <Loading />
+                        </div>
+                    }
+                </div>
+                <ExposureMask phase={props.exposure} />
+            </section>
+            <section
+                id="abx_stimulus_buttons"
+                class={classes![
+                    "self-center",
+                    "flex",
+                    "flex-row",
+                    "items-center",
+                    "justify-center",
+                    "gap-1",
+                    "md:gap-4",
+                    "my-2",
+                ]}
+            >
+                { stimulus_buttons }
+                if !all_revealed {
+                    <Button
+                        id="abx_next_button"
+                        class={classes![
+                            "border-2",
+                            "border-gray-800",
+                            "hover:text-gray-200",
+                        ]}
+                        disabled={props.loading}
+                        onclick={reveal_next}
+                    >
+                        { next_button }
+                    </Button>
+                }
+            </section>
+            <section
+                id="abx_answer_buttons"
+                class={classes![
+                    "self-center",
+                    "flex",
+                    "flex-col",
+                    "md:flex-row",
+                    "w-1/2",
+                    "items-stretch",
+                    "md:justify-center",
+                    "gap-1",
+                    "md:gap-4",
+                    "my-4",
+                    "md:mt-0",
+                ]}
+            >
+                { answer_buttons }
+            </section>
+        </>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+
+    use super::{
+        AbxStimuli,
+        ExperimentAbx,
+    };
+    use crate::{
+        click_button,
+        dom::DOM,
+        pages::experiment::{
+            Experiment,
+            ExperimentPage,
+            Exposure,
+        },
+        render_yew_component,
+        request::{
+            Comparison,
+            FakeClient,
+            VoteValue,
+        },
+        wasm_sleep_in_ms,
+        Language,
+        AVAILABLE_LANGUAGES,
+    };
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    struct ExposedAbx;
+
+    impl Experiment for ExposedAbx {
+        type Stimuli = AbxStimuli;
+
+        const EXPOSURE: Option<Exposure> = Some(Exposure {
+            duration: Duration::from_millis(300),
+            pattern: false,
+        });
+        const PROMPT: &'static str = "abx_prompt";
+    }
+
+    type ExposedAbxPage = ExperimentPage<ExposedAbx>;
+
+    fn answer_is_disabled() -> bool {
+        DOM::get_button_by_id("abx_answer_button_A")
+            .expect("Element #abx_answer_button_A to be present")
+            .has_attribute("disabled")
+    }
+
+    fn fake_client() -> FakeClient {
+        FakeClient::default().comparisons(vec![Comparison {
+            id: "abx".to_string(),
+            images: vec!["/0.png".to_string(), "/1.png".to_string()],
+            reference: None,
+            catch_trial: None,
+        }])
+    }
+
+    #[wasm_bindgen_test]
+    fn abx_markdown_exists() {
+        // add 1 to len to run even if no languages are available
+        for language_index in 0..AVAILABLE_LANGUAGES.len() + 1 {
+            let language = Language {
+                index: language_index,
+            };
+
+            assert!(language.load_file("abx_prompt.md").is_some());
+            assert!(language.load_file("abx_next_button.md").is_some());
+            assert!(language.load_file("abx_answer_button.md").is_some());
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn answer_is_enabled_once_all_stimuli_are_revealed() {
+        render_yew_component!(ExperimentAbx, fake_client());
+        wasm_sleep_in_ms(150).await;
+
+        assert!(DOM::get_element_by_id("abx_image").is_some());
+        assert!(answer_is_disabled());
+
+        click_button("abx_next_button");
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+        assert!(answer_is_disabled());
+
+        click_button("abx_next_button");
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+        assert!(!answer_is_disabled());
+        assert!(DOM::get_button_by_id("abx_next_button").is_none());
+    }
+
+    #[wasm_bindgen_test]
+    async fn answer_is_posted_with_the_trial() {
+        let api = fake_client();
+
+        render_yew_component!(ExperimentAbx, api.clone());
+        wasm_sleep_in_ms(150).await;
+
+        click_button("abx_next_button");
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+        click_button("abx_next_button");
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+        click_button("abx_answer_button_A");
+        wasm_sleep_in_ms(50).await; // allow vote to be posted
+
+        let votes = api.posted_votes();
+        assert_eq!(votes.len(), 1);
+        let VoteValue::Abx {
+            a, b, x, answer, ..
+        } = votes[0].vote_value()
+        else {
+            panic!("Vote to be an ABX answer");
+        };
+        assert_ne!(a, b);
+        assert!(x == a || x == b);
+        assert_eq!(answer, a);
+    }
+
+    #[wasm_bindgen_test]
+    async fn answer_waits_for_the_exposure_to_be_over() {
+        render_yew_component!(ExposedAbxPage, fake_client());
+        wasm_sleep_in_ms(100).await;
+
+        click_button("abx_next_button");
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+        click_button("abx_next_button");
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+        assert!(answer_is_disabled());
+        assert!(DOM::get_element_by_id("exposure_mask").is_none());
+
+        wasm_sleep_in_ms(400).await; // allow exposure to end
+        assert!(!answer_is_disabled());
+        assert!(DOM::get_element_by_id("exposure_mask").is_some());
+    }
+}
@@ -0,0 +1,172 @@
+use crate::request::VoteValue;
+
+/// One of the three stimuli of an ABX trial.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) enum AbxStimulus {
+    A,
+    B,
+    X,
+}
+
+impl AbxStimulus {
+    pub(super) fn label(&self) -> &'static str {
+        match self {
+            AbxStimulus::A => "A",
+            AbxStimulus::B => "B",
+            AbxStimulus::X => "X",
+        }
+    }
+}
+
+/// Which image is A, B and X, and in which order they are revealed.
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct AbxTrial {
+    a: String,
+    b: String,
+    x_is_a: bool,
+    reveal_order: [AbxStimulus; 3],
+}
+
+impl AbxTrial {
+    /// Draws the trial for the first two images: which is A, which one X
+    /// is, and the reveal order are all random. `random` returns numbers
+    /// in `[0, 1)`, like `Math.random`.
+    pub(super) fn new(
+        images: &[String],
+        mut random: impl FnMut() -> f64,
+    ) -> Option<Self> {
+        let [first, second] = images.get(..2)? else {
+            return None;
+        };
+        let (a, b) = match random() < 0.5 {
+            true => (first.clone(), second.clone()),
+            false => (second.clone(), first.clone()),
+        };
+        let x_is_a = random() < 0.5;
+
+        // Fisher-Yates shuffle
+        let mut reveal_order = [AbxStimulus::A, AbxStimulus::B, AbxStimulus::X];
+        for i in (1..reveal_order.len()).rev() {
+            let j = ((random() * (i + 1) as f64) as usize).min(i);
+            reveal_order.swap(i, j);
+        }
+
+        Some(Self {
+            a,
+            b,
+            x_is_a,
+            reveal_order,
+        })
+    }
+
+    pub(super) fn image(&self, stimulus: AbxStimulus) -> &str {
+        match (stimulus, self.x_is_a) {
+            (AbxStimulus::A, _) | (AbxStimulus::X, true) => &self.a,
+            (AbxStimulus::B, _) | (AbxStimulus::X, false) => &self.b,
+        }
+    }
+
+    pub(super) fn reveal_order(&self) -> &[AbxStimulus] {
+        &self.reveal_order
+    }
+
+    /// The vote for the participant matching X with `answer` (A or B).
+    pub(super) fn vote(&self, answer: AbxStimulus) -> VoteValue {
+        VoteValue::Abx {
+            a: self.a.clone(),
+            b: self.b.clone(),
+            x: self.image(AbxStimulus::X).to_string(),
+            answer: self.image(answer).to_string(),
+            reveal_order: self
+                .reveal_order
+                .iter()
+                .map(AbxStimulus::label)
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+
+    use super::{
+        AbxStimulus,
+        AbxTrial,
+    };
+    use crate::request::VoteValue;
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn images() -> Vec<String> {
+        vec!["/0.png".to_string(), "/1.png".to_string()]
+    }
+
+    /// `random` returning `values` in turn.
+    fn sequence(values: &[f64]) -> impl FnMut() -> f64 + '_ {
+        let mut values = values.iter();
+        move || *values.next().unwrap_or(&0.0)
+    }
+
+    #[wasm_bindgen_test]
+    fn x_is_one_of_a_and_b() {
+        let trial = AbxTrial::new(&images(), sequence(&[0.0, 0.9, 0.9, 0.9]))
+            .expect("Trial to be drawn");
+
+        assert_eq!(trial.image(AbxStimulus::A), "/0.png");
+        assert_eq!(trial.image(AbxStimulus::B), "/1.png");
+        assert_eq!(trial.image(AbxStimulus::X), "/1.png");
+    }
+
+    #[wasm_bindgen_test]
+    fn images_are_assigned_to_a_and_b_at_random() {
+        let trial = AbxTrial::new(&images(), sequence(&[0.9, 0.0, 0.9, 0.9]))
+            .expect("Trial to be drawn");
+
+        assert_eq!(trial.image(AbxStimulus::A), "/1.png");
+        assert_eq!(trial.image(AbxStimulus::X), "/1.png");
+    }
+
+    #[wasm_bindgen_test]
+    fn reveal_order_is_shuffled() {
+        let unshuffled =
+            AbxTrial::new(&images(), sequence(&[0.0, 0.0, 0.9, 0.9]))
+                .expect("Trial to be drawn");
+        let reversed =
+            AbxTrial::new(&images(), sequence(&[0.0, 0.0, 0.0, 0.9]))
+                .expect("Trial to be drawn");
+
+        assert_eq!(
+            unshuffled.reveal_order(),
+            [AbxStimulus::A, AbxStimulus::B, AbxStimulus::X]
+        );
+        assert_eq!(
+            reversed.reveal_order(),
+            [AbxStimulus::X, AbxStimulus::B, AbxStimulus::A]
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn trial_needs_two_images() {
+        assert!(AbxTrial::new(&images()[..1], sequence(&[])).is_none());
+    }
+
+    #[wasm_bindgen_test]
+    fn vote_holds_images_answer_and_reveal_order() {
+        let trial = AbxTrial::new(&images(), sequence(&[0.0, 0.0, 0.9, 0.9]))
+            .expect("Trial to be drawn");
+
+        assert_eq!(
+            trial.vote(AbxStimulus::B),
+            VoteValue::Abx {
+                a: "/0.png".to_string(),
+                b: "/1.png".to_string(),
+                x: "/0.png".to_string(),
+                answer: "/1.png".to_string(),
+                reveal_order: "ABX".to_string(),
+            }
+        );
+    }
+}
@@ -0,0 +1,56 @@
+/// CSS placing an element exactly over an image of `natural` size (in image
+/// pixels) fitted with `object-contain` in a square frame, in percent of
+/// the frame so it follows the size the image is rendered at. Covers the
+/// whole frame until the size is known.
+pub(super) fn image_box_style(natural: Option<(f64, f64)>) -> String {
+    let (width, height) = match natural {
+        Some((width, height)) if width > 0.0 && height > 0.0 => {
+            let longest = width.max(height);
+            (width / longest, height / longest)
+        },
+        _ => (1.0, 1.0),
+    };
+
+    format!(
+        "left: {}%; top: {}%; width: {}%; height: {}%;",
+        (1.0 - width) / 2.0 * 100.0,
+        (1.0 - height) / 2.0 * 100.0,
+        width * 100.0,
+        height * 100.0,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+
+    use super::image_box_style;
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    fn wide_image_is_letterboxed_vertically() {
+        assert_eq!(
+            image_box_style(Some((1000.0, 500.0))),
+            "left: 0%; top: 25%; width: 100%; height: 50%;"
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn tall_image_is_letterboxed_horizontally() {
+        assert_eq!(
+            image_box_style(Some((500.0, 1000.0))),
+            "left: 25%; top: 0%; width: 50%; height: 100%;"
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn image_of_unknown_size_covers_the_frame() {
+        let frame = "left: 0%; top: 0%; width: 100%; height: 100%;";
+
+        assert_eq!(image_box_style(None), frame);
+        assert_eq!(image_box_style(Some((0.0, 0.0))), frame);
+    }
+}
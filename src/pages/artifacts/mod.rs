@@ -0,0 +1,404 @@
+mod image_box;
+
+use wasm_bindgen::JsCast;
+use yew::{
+    classes,
+    function_component,
+    html,
+    use_context,
+    use_effect_with,
+    use_state_eq,
+    Callback,
+    Event,
+    Html,
+    MouseEvent,
+    TargetCast,
+    UseReducerHandle,
+};
+
+use self::image_box::image_box_style;
+use crate::{
+    pages::{
+        experiment::{
+            Experiment,
+            ExperimentPage,
+            ExposureMask,
+            StimuliProps,
+        },
+        markdown_to_yew_html,
+    },
+    request::{
+        ArtifactMark,
+        VoteValue,
+    },
+    shared_components::{
+        Button,
+        Loading,
+    },
+    Language,
+};
+
+/// Artifact localization: the participant marks where a single image looks
+/// wrong, or tells there is nothing to mark.
+pub(crate) struct Artifacts;
+
+impl Experiment for Artifacts {
+    type Stimuli = ArtifactsStimuli;
+
+    const PROMPT: &'static str = "artifacts_prompt";
+}
+
+pub(crate) type ExperimentArtifacts = ExperimentPage<Artifacts>;
+
+/// Position of the click on the element it was listened on, normalized to
+/// its size; `None` when outside of it.
+fn mark_at(event: &MouseEvent) -> Option<ArtifactMark> {
+    let image_box = event
+        .current_target()?
+        .dyn_into::<web_sys::Element>()
+        .ok()?;
+    let rect = image_box.get_bounding_client_rect();
+    if rect.width() <= 0.0 || rect.height() <= 0.0 {
+        return None;
+    }
+    let mark = ArtifactMark {
+        x: (f64::from(event.client_x()) - rect.left()) / rect.width(),
+        y: (f64::from(event.client_y()) - rect.top()) / rect.height(),
+    };
+
+    mark.is_on_image().then_some(mark)
+}
+
+#[function_component(ArtifactsStimuli)]
+pub(crate) fn artifacts_stimuli(props: &StimuliProps) -> Html {
+    let language = match use_context::<UseReducerHandle<Language>>() {
+        Some(ctx) => (*ctx).clone(),
+        None => Language::default(),
+    };
+    let marks = use_state_eq(Vec::<ArtifactMark>::new);
+    // size of the image in image pixels, once it is loaded
+    let natural_size = use_state_eq(|| None::<(f64, f64)>);
+
+    {
+        let marks = marks.clone();
+        let natural_size = natural_size.clone();
+        use_effect_with((props.loading, props.images.clone()), move |_| {
+            marks.set(Vec::new());
+            natural_size.set(None);
+        });
+    }
+
+    let undo_mark_button = language.load_file("undo_mark_button.md");
+    let undo_mark_button = markdown_to_yew_html(undo_mark_button.unwrap_or(""));
+
+    let no_artifacts_button = language.load_file("no_artifacts_button.md");
+    let no_artifacts_button =
+        markdown_to_yew_html(no_artifacts_button.unwrap_or(""));
+
+    let submit_artifacts_button =
+        language.load_file("submit_artifacts_button.md");
+    let submit_artifacts_button =
+        markdown_to_yew_html(submit_artifacts_button.unwrap_or(""));
+
+    let image = props.images.first().cloned().unwrap_or_default();
+
+    let on_image_load = {
+        let natural_size = natural_size.clone();
+        Callback::from(move |event: Event| {
+            if let Some(image) =
+                event.target_dyn_into::<web_sys::HtmlImageElement>()
+            {
+                natural_size.set(Some((
+                    f64::from(image.natural_width()),
+                    f64::from(image.natural_height()),
+                )));
+            }
+        })
+    };
+
+    let add_mark = {
+        let marks = marks.clone();
+        let answer_disabled = props.answer_disabled();
+        Callback::from(move |event: MouseEvent| {
+            if answer_disabled {
+                return;
+            }
+            if let Some(mark) = mark_at(&event) {
+                let mut new_marks = (*marks).clone();
+                new_marks.push(mark);
+                marks.set(new_marks);
+            }
+        })
+    };
+
+    let undo_mark = {
+        let marks = marks.clone();
+        Callback::from(move |_| {
+            let mut new_marks = (*marks).clone();
+            new_marks.pop();
+            marks.set(new_marks);
+        })
+    };
+
+    let vote_marks = |marks: Vec<ArtifactMark>| {
+        let onvote = props.onvote.clone();
+        Callback::from(move |_| {
+            onvote.emit(VoteValue::Artifacts(marks.clone()))
+        })
+    };
+
+    let mark_list = marks
+        .iter()
+        .enumerate()
+        .map(|(index, mark)| {
+            html! {
+                <span
+                    id={format!("artifact_mark_{index}")}
+                    class={classes![
+                        "absolute",
+                        "w-6",
+                        "h-6",
+                        "-translate-x-1/2",
+                        "-translate-y-1/2",
+                        "rounded-full",
+                        "border-2",
+                        "border-rose-600",
+                        "pointer-events-none",
+                    ]}
+                    style={format!(
+                        "left: {}%; top: {}%;",
+                        mark.x * 100.0,
+                        mark.y * 100.0,
+                    )}
+                />
+            }
+        })
+        .collect::<Html>();
+
+    html! {
+        <>
+            <section
+                id="images_list"
+                class={classes![
+                    "relative",
+                    "flex-1",
+                    "overflow-hidden",
+                    "flex",
+                    "flex-col",
+                    "items-center",
+                    "justify-center",
+                ]}
+            >
+                <div
+                    class={classes![
+                        "relative",
+                        "h-5/6",
+                        "aspect-square",
+                        "w-fit",
+                    ]}
+                >
+                    if props.loading {
+                        <div id="loading_status_button_0">
+                            <Loading />
+                        </div>
+                    } else {
+                        <img
+                            id="image_to_compare_0"
+                            class={classes![
+                                "h-full",
+                                "w-full",
+                                "object-contain",
+                            ]}
+                            src={image}
+                            alt=""
+                            draggable="false"
+                            onload={on_image_load}
+                        />
+                        // follows the image rather than the frame, so the
+                        // marks stay on the same pixels whatever its size
+                        <div
+                            id="artifact_marks"
+                            class={classes![
+                                "absolute",
+                                "cursor-crosshair",
+                            ]}
+                            style={image_box_style(*natural_size)}
+                            onclick={add_mark}
+                        >
+                            { mark_list }
+                        </div>
+                    }
+                </div>
+                <ExposureMask phase={props.exposure} />
+            </section>
+            <section
+                id="artifacts_buttons"
+                class={classes![
+                    "self-center",
+                    "flex",
+                    "flex-row",
+                    "w-1/2",
+                    "items-stretch",
+                    "justify-center",
+                    "gap-1",
+                    "md:gap-4",
+                    "my-4",
+                    "md:mt-0",
+                ]}
+            >
+                <Button
+                    id="undo_mark"
+                    class={classes![
+                        "border-2",
+                        "border-gray-800",
+                        "hover:text-gray-200",
+                        "w-full",
+                    ]}
+                    disabled={props.loading || marks.is_empty()}
+                    onclick={undo_mark}
+                >
+                    { undo_mark_button }
+                </Button>
+                if marks.is_empty() {
+                    <Button
+                        id="no_artifacts"
+                        class={classes![
+                            "border-2",
+                            "border-gray-800",
+                            "hover:text-gray-200",
+                            "w-full",
+                        ]}
+                        disabled={props.answer_disabled()}
+                        onclick={vote_marks(Vec::new())}
+                    >
+                        { no_artifacts_button }
+                    </Button>
+                } else {
+                    <Button
+                        id="submit_artifacts"
+                        class={classes![
+                            "border-2",
+                            "border-gray-800",
+                            "hover:text-gray-200",
+                            "w-full",
+                        ]}
+                        disabled={props.answer_disabled()}
+                        onclick={vote_marks((*marks).clone())}
+                    >
+                        { submit_artifacts_button }
+                    </Button>
+                }
+            </section>
+        </>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+
+    use super::ExperimentArtifacts;
+    use crate::{
+        click_button,
+        dom::DOM,
+        render_yew_component,
+        request::{
+            FakeClient,
+            VoteValue,
+        },
+        wasm_sleep_in_ms,
+        Language,
+        AVAILABLE_LANGUAGES,
+    };
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    /// Clicks the image at `(x, y)`, normalized to its size.
+    fn click_image_at(x: f64, y: f64) {
+        let image_box = DOM::get_element_by_id("artifact_marks")
+            .expect("Element #artifact_marks to be present");
+        let rect = image_box.get_bounding_client_rect();
+        let mut init = web_sys::MouseEventInit::new();
+        init.bubbles(true)
+            .client_x((rect.left() + x * rect.width()) as i32)
+            .client_y((rect.top() + y * rect.height()) as i32);
+        let event =
+            web_sys::MouseEvent::new_with_mouse_event_init_dict("click", &init)
+                .expect("MouseEvent to be constructible");
+        image_box
+            .dispatch_event(&event)
+            .expect("Event to be dispatched");
+    }
+
+    #[wasm_bindgen_test]
+    fn artifacts_markdown_exists() {
+        // add 1 to len to run even if no languages are available
+        for language_index in 0..AVAILABLE_LANGUAGES.len() + 1 {
+            let language = Language {
+                index: language_index,
+            };
+
+            assert!(language.load_file("artifacts_prompt.md").is_some());
+            assert!(language.load_file("undo_mark_button.md").is_some());
+            assert!(language.load_file("no_artifacts_button.md").is_some());
+            assert!(language.load_file("submit_artifacts_button.md").is_some());
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn clicking_the_image_marks_it_and_undo_removes_the_mark() {
+        render_yew_component!(ExperimentArtifacts);
+        wasm_sleep_in_ms(150).await;
+
+        click_image_at(0.25, 0.75);
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+        assert!(DOM::get_element_by_id("artifact_mark_0").is_some());
+        assert!(DOM::get_button_by_id("submit_artifacts").is_some());
+
+        click_button("undo_mark");
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+        assert!(DOM::get_element_by_id("artifact_mark_0").is_none());
+        assert!(DOM::get_button_by_id("no_artifacts").is_some());
+    }
+
+    #[wasm_bindgen_test]
+    async fn marks_are_posted_normalized_to_the_image() {
+        let api = FakeClient::default();
+
+        render_yew_component!(ExperimentArtifacts, api.clone());
+        wasm_sleep_in_ms(150).await;
+
+        click_image_at(0.25, 0.75);
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+        click_button("submit_artifacts");
+        wasm_sleep_in_ms(50).await; // allow vote to be posted
+
+        let votes = api.posted_votes();
+        assert_eq!(votes.len(), 1);
+        let VoteValue::Artifacts(marks) = votes[0].vote_value() else {
+            panic!("Vote to be artifact marks");
+        };
+        assert_eq!(marks.len(), 1);
+        // client coordinates are whole pixels
+        assert!((marks[0].x - 0.25).abs() < 0.02);
+        assert!((marks[0].y - 0.75).abs() < 0.02);
+    }
+
+    #[wasm_bindgen_test]
+    async fn seeing_no_artifacts_posts_no_marks() {
+        let api = FakeClient::default();
+
+        render_yew_component!(ExperimentArtifacts, api.clone());
+        wasm_sleep_in_ms(150).await;
+
+        click_button("no_artifacts");
+        wasm_sleep_in_ms(50).await; // allow vote to be posted
+
+        let votes = api.posted_votes();
+        assert_eq!(votes.len(), 1);
+        assert_eq!(votes[0].vote_value(), &VoteValue::Artifacts(Vec::new()));
+    }
+}
@@ -0,0 +1,315 @@
+mod selection;
+
+use yew::{
+    classes,
+    function_component,
+    html,
+    use_context,
+    use_effect_with,
+    use_state_eq,
+    Callback,
+    Html,
+    UseReducerHandle,
+};
+
+use self::selection::BestWorstSelection;
+use crate::{
+    pages::{
+        experiment::{
+            Experiment,
+            ExperimentPage,
+            ExposureMask,
+            StimuliProps,
+        },
+        markdown_to_yew_html,
+    },
+    shared_components::{
+        Button,
+        ImageGrid,
+    },
+    Language,
+};
+
+/// Best–worst scaling: the participant picks both the best and the worst
+/// image of a set, usually of 3 to 5.
+pub(crate) struct BestWorst;
+
+impl Experiment for BestWorst {
+    type Stimuli = BestWorstStimuli;
+
+    const PROMPT: &'static str = "best_worst_prompt";
+}
+
+pub(crate) type ExperimentBestWorst = ExperimentPage<BestWorst>;
+
+#[function_component(BestWorstStimuli)]
+pub(crate) fn best_worst_stimuli(props: &StimuliProps) -> Html {
+    let language = match use_context::<UseReducerHandle<Language>>() {
+        Some(ctx) => (*ctx).clone(),
+        None => Language::default(),
+    };
+    let selection = use_state_eq(BestWorstSelection::default);
+
+    {
+        let selection = selection.clone();
+        use_effect_with((props.loading, props.images.clone()), move |_| {
+            selection.set(BestWorstSelection::default())
+        });
+    }
+
+    let submit_button = language.load_file("submit_best_worst_button.md");
+    let submit_button = markdown_to_yew_html(submit_button.unwrap_or(""));
+
+    let reset_button = language.load_file("reset_best_worst_button.md");
+    let reset_button = markdown_to_yew_html(reset_button.unwrap_or(""));
+
+    let toggle_pick = {
+        let selection = selection.clone();
+        let answer_disabled = props.answer_disabled();
+        Callback::from(move |index: usize| {
+            if answer_disabled {
+                return;
+            }
+            selection.set(selection.toggle(index))
+        })
+    };
+
+    let reset_selection = {
+        let selection = selection.clone();
+        Callback::from(move |_| selection.set(BestWorstSelection::default()))
+    };
+
+    let vote_value = selection.vote(&props.images);
+
+    let submit_selection = {
+        let vote_value = vote_value.clone();
+        let onvote = props.onvote.clone();
+        Callback::from(move |_| {
+            if let Some(vote_value) = vote_value.clone() {
+                onvote.emit(vote_value);
+            }
+        })
+    };
+
+    let pick_badges = (0..props.images.len())
+        .map(|index| {
+            let Some(pick) = selection.pick(index) else {
+                return html! {};
+            };
+            let label = language
+                .load_file(&format!("best_worst_{}_label.md", pick.name()));
+            let label = markdown_to_yew_html(label.unwrap_or(""));
+
+            html! {
+                <span
+                    id={format!("{}_image_{index}", pick.name())}
+                    class={classes![
+                        "absolute",
+                        "top-4",
+                        "left-4",
+                        "px-3",
+                        "py-1",
+                        "rounded-full",
+                        "bg-gray-800",
+                        "text-gray-100",
+                    ]}
+                >
+                    { label }
+                </span>
+            }
+        })
+        .collect::<Vec<_>>();
+
+    html! {
+        <>
+            <ImageGrid
+                loading={props.loading}
+                images={props.images.clone()}
+                reference={props.reference.clone()}
+                onclick={toggle_pick}
+                badges={pick_badges}
+            >
+                <ExposureMask phase={props.exposure} />
+            </ImageGrid>
+            <section
+                id="best_worst_buttons"
+                class={classes![
+                    "self-center",
+                    "flex",
+                    "flex-row",
+                    "w-1/2",
+                    "items-stretch",
+                    "justify-center",
+                    "gap-1",
+                    "md:gap-4",
+                    "my-4",
+                    "md:mt-0",
+                ]}
+            >
+                <Button
+                    id="reset_best_worst"
+                    class={classes![
+                        "border-2",
+                        "border-gray-800",
+                        "hover:text-gray-200",
+                        "w-full",
+                    ]}
+                    disabled={props.loading}
+                    onclick={reset_selection}
+                >
+                    { reset_button }
+                </Button>
+                <Button
+                    id="submit_best_worst"
+                    class={classes![
+                        "border-2",
+                        "border-gray-800",
+                        "hover:text-gray-200",
+                        "w-full",
+                    ]}
+                    disabled={props.answer_disabled() || vote_value.is_none()}
+                    onclick={submit_selection}
+                >
+                    { submit_button }
+                </Button>
+            </section>
+        </>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+
+    use super::ExperimentBestWorst;
+    use crate::{
+        click_button,
+        dom::DOM,
+        render_yew_component,
+        request::{
+            Comparison,
+            FakeClient,
+            VoteValue,
+        },
+        wasm_sleep_in_ms,
+        Language,
+        AVAILABLE_LANGUAGES,
+    };
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn submit_is_disabled() -> bool {
+        DOM::get_button_by_id("submit_best_worst")
+            .expect("Element #submit_best_worst to be present")
+            .has_attribute("disabled")
+    }
+
+    fn fake_client() -> FakeClient {
+        FakeClient::default().comparisons(vec![Comparison {
+            id: "best_worst".to_string(),
+            images: (0..4).map(|index| format!("/{index}.png")).collect(),
+            reference: None,
+            catch_trial: None,
+        }])
+    }
+
+    #[wasm_bindgen_test]
+    fn best_worst_markdown_exists() {
+        // add 1 to len to run even if no languages are available
+        for language_index in 0..AVAILABLE_LANGUAGES.len() + 1 {
+            let language = Language {
+                index: language_index,
+            };
+
+            assert!(language.load_file("best_worst_prompt.md").is_some());
+            assert!(language.load_file("best_worst_best_label.md").is_some());
+            assert!(language.load_file("best_worst_worst_label.md").is_some());
+            assert!(language.load_file("reset_best_worst_button.md").is_some());
+            assert!(language
+                .load_file("submit_best_worst_button.md")
+                .is_some());
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn clicking_images_picks_best_then_worst() {
+        render_yew_component!(ExperimentBestWorst, fake_client());
+        wasm_sleep_in_ms(150).await;
+
+        click_button("image_to_compare_button_3");
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+        assert!(DOM::get_element_by_id("best_image_3").is_some());
+        assert!(submit_is_disabled());
+
+        click_button("image_to_compare_button_1");
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+        assert!(DOM::get_element_by_id("worst_image_1").is_some());
+        assert!(!submit_is_disabled());
+    }
+
+    #[wasm_bindgen_test]
+    async fn reset_button_clears_picks() {
+        render_yew_component!(ExperimentBestWorst, fake_client());
+        wasm_sleep_in_ms(150).await;
+
+        click_button("image_to_compare_button_0");
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+        click_button("reset_best_worst");
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+
+        assert!(DOM::get_element_by_id("best_image_0").is_none());
+    }
+
+    #[wasm_bindgen_test]
+    async fn submitting_posts_best_and_worst() {
+        let api = fake_client();
+
+        render_yew_component!(ExperimentBestWorst, api.clone());
+        wasm_sleep_in_ms(150).await;
+
+        click_button("image_to_compare_button_2");
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+        click_button("image_to_compare_button_0");
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+        click_button("submit_best_worst");
+        wasm_sleep_in_ms(50).await; // allow vote to be posted
+
+        let votes = api.posted_votes();
+        assert_eq!(votes.len(), 1);
+        assert_eq!(
+            votes[0].vote_value(),
+            &VoteValue::BestWorst {
+                best: "/2.png".to_string(),
+                worst: "/0.png".to_string(),
+            }
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn reference_is_shown_apart_from_the_images() {
+        let api = FakeClient::default().comparisons(vec![Comparison {
+            id: "best_worst".to_string(),
+            images: (0..3).map(|index| format!("/{index}.png")).collect(),
+            reference: Some("/reference.png".to_string()),
+            catch_trial: None,
+        }]);
+
+        render_yew_component!(ExperimentBestWorst, api);
+        wasm_sleep_in_ms(150).await;
+
+        let reference = DOM::get_element_by_id("reference_image")
+            .and_then(|image| image.get_attribute("src"))
+            .expect("Reference image to be present");
+        assert_eq!(reference, "/reference.png");
+        assert!(DOM::get_element_by_id("reference_label").is_some());
+        assert_eq!(
+            DOM::get_images_by_id_contains("image_to_compare_")
+                .unwrap_or_default()
+                .len(),
+            3
+        );
+    }
+}
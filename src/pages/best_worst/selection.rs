@@ -0,0 +1,144 @@
+use crate::request::VoteValue;
+
+/// What an image was picked as.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) enum Pick {
+    Best,
+    Worst,
+}
+
+impl Pick {
+    /// Name used in element ids and markdown file names.
+    pub(super) fn name(&self) -> &'static str {
+        match self {
+            Pick::Best => "best",
+            Pick::Worst => "worst",
+        }
+    }
+}
+
+/// Images picked as the best and the worst so far, as indexes into the
+/// images of the comparison.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(super) struct BestWorstSelection {
+    best: Option<usize>,
+    worst: Option<usize>,
+}
+
+impl BestWorstSelection {
+    pub(super) fn pick(&self, index: usize) -> Option<Pick> {
+        match (self.best == Some(index), self.worst == Some(index)) {
+            (true, _) => Some(Pick::Best),
+            (_, true) => Some(Pick::Worst),
+            _ => None,
+        }
+    }
+
+    /// Picks the image as the best, then another one as the worst; picking
+    /// an image again unpicks it. Once both are picked, other images are
+    /// left alone.
+    pub(super) fn toggle(self, index: usize) -> Self {
+        match self.pick(index) {
+            Some(Pick::Best) => Self { best: None, ..self },
+            Some(Pick::Worst) => Self {
+                worst: None,
+                ..self
+            },
+            None if self.best.is_none() => Self {
+                best: Some(index),
+                ..self
+            },
+            None if self.worst.is_none() => Self {
+                worst: Some(index),
+                ..self
+            },
+            None => self,
+        }
+    }
+
+    /// The answer, once two different images are picked.
+    pub(super) fn vote(&self, images: &[String]) -> Option<VoteValue> {
+        let (best, worst) = self.best.zip(self.worst)?;
+        if best == worst {
+            return None;
+        }
+
+        Some(VoteValue::BestWorst {
+            best: images.get(best)?.clone(),
+            worst: images.get(worst)?.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+
+    use super::{
+        BestWorstSelection,
+        Pick,
+    };
+    use crate::request::VoteValue;
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn images() -> Vec<String> {
+        (0..3).map(|index| format!("/{index}.png")).collect()
+    }
+
+    #[wasm_bindgen_test]
+    fn first_pick_is_best_and_second_is_worst() {
+        let selection = BestWorstSelection::default().toggle(2).toggle(0);
+
+        assert_eq!(selection.pick(2), Some(Pick::Best));
+        assert_eq!(selection.pick(0), Some(Pick::Worst));
+        assert_eq!(selection.pick(1), None);
+        assert_eq!(
+            selection.vote(&images()),
+            Some(VoteValue::BestWorst {
+                best: "/2.png".to_string(),
+                worst: "/0.png".to_string(),
+            })
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn picking_an_image_again_unpicks_it() {
+        let selection = BestWorstSelection::default().toggle(2).toggle(0);
+
+        let selection = selection.toggle(2);
+        assert_eq!(selection.pick(2), None);
+        assert_eq!(selection.vote(&images()), None);
+
+        // the best is picked again, the worst stays
+        let selection = selection.toggle(1);
+        assert_eq!(selection.pick(1), Some(Pick::Best));
+        assert_eq!(selection.pick(0), Some(Pick::Worst));
+    }
+
+    #[wasm_bindgen_test]
+    fn other_images_are_ignored_once_both_are_picked() {
+        let selection = BestWorstSelection::default().toggle(2).toggle(0);
+
+        assert_eq!(selection.toggle(1), selection);
+    }
+
+    #[wasm_bindgen_test]
+    fn best_alone_is_not_an_answer() {
+        let selection = BestWorstSelection::default().toggle(1);
+
+        assert_eq!(selection.vote(&images()), None);
+    }
+
+    #[wasm_bindgen_test]
+    fn same_image_is_never_both_best_and_worst() {
+        let selection = BestWorstSelection {
+            best: Some(1),
+            worst: Some(1),
+        };
+
+        assert_eq!(selection.vote(&images()), None);
+    }
+}
@@ -2,20 +2,61 @@ use yew::{
     classes,
     function_component,
     html,
+    use_state_eq,
+    Callback,
     Html,
+    PointerEvent,
     Properties,
 };
 
-use crate::shared_components::Loading;
+use crate::shared_components::{
+    frame_position,
+    loupe_style,
+    Loading,
+    Loupe,
+};
 
 #[derive(Properties, PartialEq)]
 pub(super) struct ImageListProps {
     pub(super) loading: bool,
     pub(super) images: Vec<String>,
+    /// Magnification of the loupe following the pointer, while it is on.
+    #[prop_or_default]
+    pub(super) loupe: Option<f64>,
 }
 
 #[function_component(ImageList)]
 pub(super) fn image_list(props: &ImageListProps) -> Html {
+    // shared by all the images, so each loupe shows the same region
+    let loupe_position = use_state_eq(|| None::<(f64, f64)>);
+    let loupe_on = props.loupe.is_some();
+
+    let on_pointer_move = {
+        let loupe_position = loupe_position.clone();
+        Callback::from(move |event: PointerEvent| {
+            if loupe_on {
+                loupe_position.set(frame_position(&event).map(|(at, _)| at));
+            }
+        })
+    };
+
+    let on_pointer_up = {
+        let loupe_position = loupe_position.clone();
+        Callback::from(move |event: PointerEvent| {
+            // a touch loupe only shows while the finger is down
+            if event.pointer_type() == "touch" {
+                loupe_position.set(None);
+            }
+        })
+    };
+
+    let on_pointer_leave = {
+        let loupe_position = loupe_position.clone();
+        Callback::from(move |_: PointerEvent| loupe_position.set(None))
+    };
+
+    let loupe = props.loupe.zip(*loupe_position);
+
     props
         .images
         .iter()
@@ -41,18 +82,44 @@ pub(super) fn image_list(props: &ImageListProps) -> Html {
                     <div
                         id={format!("image_to_compare_button_{index}")}
                         class={classes![
+                            "relative",
                             "h-1/2",
                             "md:h-5/6",
                             "aspect-square",
                             "w-fit",
+                            loupe_on.then_some("touch-none"),
                         ]}
+                        onpointerdown={on_pointer_move.clone()}
+                        onpointermove={on_pointer_move.clone()}
+                        onpointerup={on_pointer_up.clone()}
+                        onpointercancel={on_pointer_up.clone()}
+                        onpointerleave={on_pointer_leave.clone()}
                     >
                         <img
                             id={format!("image_to_compare_{index}")}
-                            class={classes!["h-full"]}
+                            class={classes![
+                                "h-full",
+                                "w-full",
+                                "object-contain",
+                            ]}
                             src={image.clone()}
                             alt=""
+                            draggable="false"
                         />
+                        if let Some((magnification, (x, y))) = loupe {
+                            <Loupe
+                                id={format!("loupe_{index}")}
+                                image={image.clone()}
+                                x={x}
+                                y={y}
+                                image_style={loupe_style(
+                                    1.0,
+                                    (0.0, 0.0),
+                                    (x, y),
+                                    magnification,
+                                )}
+                            />
+                        }
                     </div>
                 }
             }
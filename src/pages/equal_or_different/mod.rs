@@ -1,66 +1,72 @@
-mod change_user_modal;
-mod dot_button;
-mod finish_comparing_modal;
-mod header;
 mod image_list;
-mod instructions_card;
-mod instructions_modal;
-mod prompt;
 
 use yew::{
     classes,
     function_component,
     html,
     use_context,
-    use_effect_with,
     use_state_eq,
     Callback,
     Html,
     UseReducerHandle,
 };
 
-use self::{
-    header::Header,
-    image_list::ImageList,
-    instructions_modal::InstructionsModal,
-    prompt::Prompt,
-};
+use self::image_list::ImageList;
 use crate::{
-    assets::QuestionMarkCircle,
-    pages::markdown_to_yew_html,
+    pages::{
+        experiment::{
+            Experiment,
+            ExperimentPage,
+            ExposureMask,
+            StimuliProps,
+        },
+        markdown_to_yew_html,
+    },
     request::{
-        get_comparison_for_user,
-        get_user,
-        post_vote,
-        Comparison,
-        User,
-        Vote,
+        Presentation,
         VoteValue,
     },
     shared_components::{
         Button,
-        FatalErrorModal,
-        Footer,
+        Flicker,
+        ReferenceImage,
+        Wipe,
     },
     Language,
 };
 
-#[function_component(ImagesToCompare)]
-pub(crate) fn images_to_compare() -> Html {
+/// The participant judges whether the images are the same or different.
+pub(crate) struct EqualOrDifferent;
+
+impl Experiment for EqualOrDifferent {
+    type Stimuli = EqualOrDifferentStimuli;
+
+    const LOUPE_MAGNIFICATION: Option<f64> = Some(3.0);
+    const PRESENTATIONS: &'static [Presentation] = &[
+        Presentation::SideBySide,
+        Presentation::Wipe,
+        Presentation::Flicker,
+    ];
+    const PROMPT: &'static str = "same_or_different_prompt";
+}
+
+pub(crate) type ExperimentEqualOrDifferent = ExperimentPage<EqualOrDifferent>;
+
+#[function_component(EqualOrDifferentStimuli)]
+pub(crate) fn equal_or_different_stimuli(props: &StimuliProps) -> Html {
     let language = match use_context::<UseReducerHandle<Language>>() {
         Some(ctx) => (*ctx).clone(),
         None => Language::default(),
     };
-    let loading = use_state_eq(|| true);
-    let show_fatal_error_modal = use_state_eq(|| false);
-    let show_instructions_modal = use_state_eq(|| false);
-    let comparison_state = use_state_eq(|| None::<Comparison>);
-    let user_state = use_state_eq(|| User::default());
+    let loupe_on = use_state_eq(|| false);
 
-    let instructions_button_sr =
-        language.load_file("instructions_button_sr.md");
-    let instructions_button_sr =
-        markdown_to_yew_html(instructions_button_sr.unwrap_or(""));
+    let toggle_loupe = {
+        let loupe_on = loupe_on.clone();
+        Callback::from(move |_| loupe_on.set(!*loupe_on))
+    };
+
+    let loupe_button = language.load_file("loupe_button.md");
+    let loupe_button = markdown_to_yew_html(loupe_button.unwrap_or(""));
 
     let vote_same_button_text = language.load_file("vote_same_button.md");
     let vote_same_button_text =
@@ -71,127 +77,33 @@ pub(crate) fn images_to_compare() -> Html {
     let vote_different_button_text =
         markdown_to_yew_html(vote_different_button_text.unwrap_or(""));
 
-    let reload = {
-        let loading = loading.clone();
-        let comparison_state = comparison_state.clone();
-        Callback::from(move |_| {
-            loading.set(true);
-            comparison_state.set(None);
-        })
-    };
-
-    let close_fatal_error_modal = {
-        let show_fatal_error_modal = show_fatal_error_modal.clone();
-        Callback::from(move |_| {
-            show_fatal_error_modal.set(false);
-        })
-    };
-
-    let open_instructions_modal = {
-        let show_instructions_modal = show_instructions_modal.clone();
-        Callback::from(move |_| {
-            show_instructions_modal.set(true);
-        })
-    };
+    let reference = props.reference.clone().filter(|_| !props.loading);
 
-    let close_instructions_modal = {
-        let show_instructions_modal = show_instructions_modal.clone();
-        Callback::from(move |_| {
-            show_instructions_modal.set(false);
-        })
-    };
-
-    let on_vote = {
-        let loading = loading.clone();
-        let show_fatal_error_modal = show_fatal_error_modal.clone();
-        let comparison_state = comparison_state.clone();
-        let user_state = user_state.clone();
-
-        Callback::from(move |vote_value: VoteValue| {
-            loading.set(true);
-            let show_fatal_error_modal = show_fatal_error_modal.clone();
-            let comparison_state = comparison_state.clone();
-            let user_state = user_state.clone();
-            wasm_bindgen_futures::spawn_local(async move {
-                let vote = Vote::build(
-                    (*comparison_state)
-                        .clone()
-                        .expect("BUG: Comparison expected"),
-                )
-                .user(user_state.id.clone())
-                .vote(vote_value);
-                let response = post_vote(vote).await;
-                match response {
-                    Ok(_) => comparison_state.set(None),
-                    Err(_) => show_fatal_error_modal.set(true),
-                }
-            });
-        })
-    };
-
-    let fetch_comparison = {
-        let loading = loading.clone();
-        let show_fatal_error_modal = show_fatal_error_modal.clone();
-        let show_instructions_modal = show_instructions_modal.clone();
-        let comparison_state = comparison_state.clone();
-        let user_state = user_state.clone();
-
-        || {
-            if *loading && !*show_fatal_error_modal {
-                wasm_bindgen_futures::spawn_local(async move {
-                    let user_response = get_user().await;
-                    let comparison_response = match user_response {
-                        Ok(ref user) => {
-                            get_comparison_for_user(
-                                user.id.clone(),
-                                crate::DEFAULT_PAGE.to_string(),
-                            )
-                            .await
-                        },
-                        Err(_) => Err(()),
-                    };
-                    match (user_response, comparison_response) {
-                        (Ok(user), Ok(comparison)) => {
-                            loading.set(false);
-                            if user.votes == 0 {
-                                show_instructions_modal.set(true);
-                            }
-                            user_state.set(user);
-                            comparison_state.set(Some(comparison));
-                        },
-                        (_, _) => {
-                            show_fatal_error_modal.set(true);
-                        },
-                    }
-                });
-            }
-        }
-    };
-
-    {
-        let comparison_state = comparison_state.clone();
-
-        use_effect_with(comparison_state, move |_| fetch_comparison());
-    }
-
-    let image_list_to_display = match (*comparison_state).clone() {
-        Some(comparison) => comparison.images,
-        None => Comparison::default().images,
+    let images = match (props.presentation, props.loading) {
+        (Presentation::Wipe, false) => html! {
+            <Wipe images={props.images.clone()} />
+        },
+        (Presentation::Flicker, false) => html! {
+            <Flicker
+                images={props.images.clone()}
+                interval={props.flicker_interval}
+            />
+        },
+        _ => html! {
+            <ImageList
+                loading={props.loading}
+                images={props.images.clone()}
+                loupe={props.loupe_magnification.filter(|_| *loupe_on)}
+            />
+        },
     };
 
     html! {
-        <section
-            id="compare"
-            class={classes!["h-full", "flex", "flex-col"]}
-        >
-            <Header
-                user={(*user_state).clone()}
-                onreload={reload}
-            />
-            <Prompt />
+        <>
             <section
                 id="images_list"
                 class={classes![
+                    "relative",
                     "flex-1",
                     "overflow-hidden",
                     "flex",
@@ -202,11 +114,41 @@ pub(crate) fn images_to_compare() -> Html {
                     "gap-4",
                 ]}
             >
-                <ImageList
-                    loading={(*loading).clone()}
-                    images={image_list_to_display}
-                />
+                if let Some(reference) = reference {
+                    <ReferenceImage image={reference} />
+                }
+                { images }
+                <ExposureMask phase={props.exposure} />
             </section>
+            if props.presentation == Presentation::SideBySide
+                && props.loupe_magnification.is_some()
+            {
+                <section
+                    id="zoom_controls"
+                    class={classes![
+                        "self-center",
+                        "flex",
+                        "flex-row",
+                        "items-center",
+                        "justify-center",
+                        "my-2",
+                    ]}
+                >
+                    <Button
+                        id="loupe_button"
+                        class={classes![
+                            "border-2",
+                            "border-gray-800",
+                            "hover:text-gray-200",
+                            loupe_on.then_some("bg-black/[0.2]"),
+                        ]}
+                        disabled={props.loading}
+                        onclick={toggle_loupe}
+                    >
+                        { loupe_button }
+                    </Button>
+                </section>
+            }
             <section
                 id="vote_buttons"
                 class={classes![
@@ -231,9 +173,10 @@ pub(crate) fn images_to_compare() -> Html {
                         "hover:text-gray-200",
                         "w-full",
                     ]}
+                    disabled={props.answer_disabled()}
                     onclick={
-                        let on_vote = on_vote.clone();
-                        move |_| on_vote.emit(VoteValue::Equal)
+                        let onvote = props.onvote.clone();
+                        move |_| onvote.emit(VoteValue::Equal)
                     }
                 >
                     { vote_same_button_text }
@@ -246,37 +189,16 @@ pub(crate) fn images_to_compare() -> Html {
                         "hover:text-gray-200",
                         "w-full",
                     ]}
+                    disabled={props.answer_disabled()}
                     onclick={
-                        let on_vote = on_vote.clone();
-                        move |_| on_vote.emit(VoteValue::Different)
+                        let onvote = props.onvote.clone();
+                        move |_| onvote.emit(VoteValue::Different)
                     }
                 >
                     { vote_different_button_text }
                 </Button>
             </section>
-            <Footer>
-                <Button
-                    id={"open_instructions_modal_button"}
-                    onclick={open_instructions_modal}
-                >
-                    <QuestionMarkCircle
-                        class={classes![
-                            "h-8",
-                            "stroke-gray-100",
-                        ]}
-                    />
-                    <span class={classes!["sr-only"]}>
-                        { instructions_button_sr }
-                    </span>
-                </Button>
-            </Footer>
-            if *show_instructions_modal && !*show_fatal_error_modal {
-                <InstructionsModal onclose={close_instructions_modal} />
-            }
-            if *show_fatal_error_modal {
-                <FatalErrorModal onclose={close_fatal_error_modal} />
-            }
-        </section>
+        </>
     }
 }
 
@@ -290,18 +212,14 @@ mod tests {
         wasm_bindgen_test_configure,
     };
 
-    use super::ImagesToCompare;
+    use super::ExperimentEqualOrDifferent;
     use crate::{
         dom::DOM,
         markdown_to_decoded_html,
         render_yew_component,
         request::{
-            get_user,
-            images::GET_IMAGES_RETURNS_OK,
-            user::{
-                GET_USER_RETURNS_OK,
-                VOTES_TO_DISPLAY,
-            },
+            FakeClient,
+            Presentation,
         },
         wasm_sleep_in_ms,
         Language,
@@ -312,282 +230,21 @@ mod tests {
     wasm_bindgen_test_configure!(run_in_browser);
 
     #[wasm_bindgen_test]
-    fn change_user_button_markdown_exists() {
+    fn same_or_different_prompt_markdown_exists() {
         // add 1 to len to run even if no languages are available
         for language_index in 0..AVAILABLE_LANGUAGES.len() + 1 {
             let language = Language {
                 index: language_index,
             };
-            let file = language.load_file("change_user_button.md");
+            let file = language.load_file("same_or_different_prompt.md");
 
             assert!(file.is_some())
         }
     }
 
-    #[wasm_bindgen_test]
-    async fn button_to_change_user_exists() {
-        // add 1 to len to run even if no languages are available
-        for language_index in 0..AVAILABLE_LANGUAGES.len() + 1 {
-            DEFAULT_LANGUAGE.store(language_index, Ordering::SeqCst);
-
-            render_yew_component!(ImagesToCompare);
-            wasm_sleep_in_ms(150).await;
-
-            let language = Language::default();
-            let expected = language.load_file("change_user_button.md");
-            let expected = markdown_to_decoded_html(expected.unwrap_or(""));
-
-            assert!(DOM::has_button_with_inner_html(&expected));
-        }
-    }
-
-    #[wasm_bindgen_test]
-    async fn change_user_modal_is_closed_by_default() {
-        render_yew_component!(ImagesToCompare);
-        wasm_sleep_in_ms(150).await;
-
-        assert!(DOM::get_element_by_id("change_user_modal").is_none());
-    }
-
-    #[wasm_bindgen_test]
-    async fn button_to_change_user_shows_change_user_modal() {
-        render_yew_component!(ImagesToCompare);
-        wasm_sleep_in_ms(150).await;
-
-        let button = DOM::get_button_by_id("change_user_button")
-            .expect("Element #change_user_button to be present")
-            .dyn_into::<web_sys::HtmlElement>()
-            .expect("Element to be castable to HtmlElement");
-
-        button.click();
-        wasm_sleep_in_ms(50).await; // allow page to re-render
-        assert!(DOM::get_element_by_id("change_user_modal").is_some());
-    }
-
-    #[wasm_bindgen_test]
-    async fn change_user_modal_can_be_closed() {
-        render_yew_component!(ImagesToCompare);
-        wasm_sleep_in_ms(150).await;
-
-        let open_button = DOM::get_button_by_id("change_user_button")
-            .expect("Element #change_user_button to be present")
-            .dyn_into::<web_sys::HtmlElement>()
-            .expect("Element to be castable to HtmlElement");
-
-        open_button.click();
-        wasm_sleep_in_ms(50).await; // allow page to re-render
-
-        let close_button =
-            DOM::get_button_by_id("close_change_user_modal_button")
-                .expect("Element #close_change_user_modal_button to be present")
-                .dyn_into::<web_sys::HtmlElement>()
-                .expect("Element to be castable to HtmlElement");
-
-        close_button.click();
-        wasm_sleep_in_ms(50).await; // allow page to re-render
-        assert!(DOM::get_element_by_id("change_user_modal").is_none());
-    }
-
-    #[wasm_bindgen_test]
-    async fn change_user_modal_can_be_closed_by_cancel_button() {
-        render_yew_component!(ImagesToCompare);
-        wasm_sleep_in_ms(150).await;
-
-        let open_button = DOM::get_button_by_id("change_user_button")
-            .expect("Element #change_user_button to be present")
-            .dyn_into::<web_sys::HtmlElement>()
-            .expect("Element to be castable to HtmlElement");
-
-        open_button.click();
-        wasm_sleep_in_ms(50).await; // allow page to re-render
-
-        let close_button = DOM::get_button_by_id("change_user_cancel_button")
-            .expect("Element #change_user_cancel_button to be present")
-            .dyn_into::<web_sys::HtmlElement>()
-            .expect("Element to be castable to HtmlElement");
-
-        close_button.click();
-        wasm_sleep_in_ms(50).await; // allow page to re-render
-        assert!(DOM::get_element_by_id("change_user_modal").is_none());
-    }
-
-    #[wasm_bindgen_test]
-    async fn confirm_reset_user_in_change_user_modal_reloads_page() {
-        GET_IMAGES_RETURNS_OK.store(true, Ordering::SeqCst);
-        GET_USER_RETURNS_OK.store(true, Ordering::SeqCst);
-        DOM::local_storage()
-            .unwrap()
-            .set_item("user_id", "123456")
-            .unwrap();
-        VOTES_TO_DISPLAY.store(rand::random(), Ordering::SeqCst);
-
-        render_yew_component!(ImagesToCompare);
-        wasm_sleep_in_ms(150).await;
-
-        let open_button = DOM::get_button_by_id("change_user_button")
-            .expect("Element #change_user_button to be present")
-            .dyn_into::<web_sys::HtmlElement>()
-            .expect("Element to be castable to HtmlElement");
-
-        open_button.click();
-        wasm_sleep_in_ms(50).await; // allow page to re-render
-
-        let confirm_button =
-            DOM::get_button_by_id("change_user_confirm_button")
-                .expect("Element #change_user_confirm_button to be present")
-                .dyn_into::<web_sys::HtmlElement>()
-                .expect("Element to be castable to HtmlElement");
-
-        confirm_button.click();
-        wasm_sleep_in_ms(150).await; // allow page to re-render
-        assert!(DOM::get_element_by_id("instructions_modal").is_some());
-    }
-
-    #[wasm_bindgen_test]
-    async fn button_to_finish_comparing_exists() {
-        render_yew_component!(ImagesToCompare);
-        wasm_sleep_in_ms(150).await;
-
-        assert!(DOM::get_button_by_id("finish_comparing_button").is_some());
-    }
-
-    #[wasm_bindgen_test]
-    fn finish_comparing_button_markdown_exists() {
-        // add 1 to len to run even if no languages are available
-        for language_index in 0..AVAILABLE_LANGUAGES.len() + 1 {
-            let language = Language {
-                index: language_index,
-            };
-            let file = language.load_file("finish_comparing_button.md");
-
-            assert!(file.is_some())
-        }
-    }
-
-    #[wasm_bindgen_test]
-    async fn button_to_finish_comparing_shows_user_votes() {
-        GET_IMAGES_RETURNS_OK.store(true, Ordering::SeqCst);
-        GET_USER_RETURNS_OK.store(true, Ordering::SeqCst);
-        DOM::local_storage()
-            .unwrap()
-            .set_item("user_id", "123456")
-            .unwrap();
-        VOTES_TO_DISPLAY.store(rand::random(), Ordering::SeqCst);
-
-        // add 1 to len to run even if no languages are available
-        for language_index in 0..AVAILABLE_LANGUAGES.len() + 1 {
-            DEFAULT_LANGUAGE.store(language_index, Ordering::SeqCst);
-
-            render_yew_component!(ImagesToCompare);
-            wasm_sleep_in_ms(150).await;
-
-            let user = get_user().await.expect("request to return Ok response");
-
-            let language = Language::default();
-            let expected = language.load_file("finish_comparing_button.md");
-            let expected = expected
-                .unwrap_or("")
-                .replace("{votes}", &user.votes.to_string());
-            let expected = markdown_to_decoded_html(&expected);
-
-            let button = DOM::get_button_by_id("finish_comparing_button")
-                .expect("finish_comparing_button to be present");
-
-            assert_eq!(button.inner_html(), expected);
-        }
-    }
-
-    #[wasm_bindgen_test]
-    async fn finish_comparing_modal_is_closed_by_default() {
-        render_yew_component!(ImagesToCompare);
-        wasm_sleep_in_ms(150).await;
-
-        assert!(DOM::get_element_by_id("finish_comparing_modal").is_none());
-    }
-
-    #[wasm_bindgen_test]
-    async fn button_to_finish_comparing_shows_change_user_modal() {
-        render_yew_component!(ImagesToCompare);
-        wasm_sleep_in_ms(150).await;
-
-        let button = DOM::get_button_by_id("finish_comparing_button")
-            .expect("Element #finish_comparing_button to be present")
-            .dyn_into::<web_sys::HtmlElement>()
-            .expect("Element to be castable to HtmlElement");
-
-        button.click();
-        wasm_sleep_in_ms(50).await; // allow page to re-render
-        assert!(DOM::get_element_by_id("finish_comparing_modal").is_some());
-    }
-
-    #[wasm_bindgen_test]
-    async fn finish_comparing_modal_can_be_closed() {
-        render_yew_component!(ImagesToCompare);
-        wasm_sleep_in_ms(150).await;
-
-        let open_button = DOM::get_button_by_id("finish_comparing_button")
-            .expect("Element #finish_comparing_button to be present")
-            .dyn_into::<web_sys::HtmlElement>()
-            .expect("Element to be castable to HtmlElement");
-
-        open_button.click();
-        wasm_sleep_in_ms(50).await; // allow page to re-render
-
-        let close_button =
-            DOM::get_button_by_id("close_finish_comparing_modal_button")
-                .expect(
-                    "Element #close_finish_comparing_modal_button to be \
-                     present",
-                )
-                .dyn_into::<web_sys::HtmlElement>()
-                .expect("Element to be castable to HtmlElement");
-
-        close_button.click();
-        wasm_sleep_in_ms(50).await; // allow page to re-render
-        assert!(DOM::get_element_by_id("finish_comparing_modal").is_none());
-    }
-
-    #[wasm_bindgen_test]
-    async fn show_fatal_error_modal_when_get_user_returns_error() {
-        GET_IMAGES_RETURNS_OK.store(true, Ordering::SeqCst);
-        GET_USER_RETURNS_OK.store(false, Ordering::SeqCst);
-
-        render_yew_component!(ImagesToCompare);
-        wasm_sleep_in_ms(150).await;
-
-        assert!(DOM::get_element_by_id("fatal_error_modal").is_some());
-    }
-
-    #[wasm_bindgen_test()]
-    async fn show_fatal_error_modal_when_get_images_returns_error() {
-        GET_IMAGES_RETURNS_OK.store(false, Ordering::SeqCst);
-        GET_USER_RETURNS_OK.store(true, Ordering::SeqCst);
-
-        render_yew_component!(ImagesToCompare);
-        wasm_sleep_in_ms(150).await;
-
-        assert!(DOM::get_element_by_id("fatal_error_modal").is_some());
-    }
-
-    #[wasm_bindgen_test]
-    async fn two_images_to_compare_exist() {
-        GET_IMAGES_RETURNS_OK.store(true, Ordering::SeqCst);
-        GET_USER_RETURNS_OK.store(true, Ordering::SeqCst);
-
-        render_yew_component!(ImagesToCompare);
-        wasm_sleep_in_ms(150).await;
-
-        assert_eq!(
-            DOM::get_images_by_id_contains("image_to_compare_")
-                .unwrap_or(vec![])
-                .len(),
-            2
-        );
-    }
-
     #[wasm_bindgen_test]
     async fn button_to_vote_same_exists() {
-        render_yew_component!(ImagesToCompare);
+        render_yew_component!(ExperimentEqualOrDifferent);
         wasm_sleep_in_ms(150).await;
 
         assert!(DOM::get_button_by_id("vote_same").is_some());
@@ -595,7 +252,7 @@ mod tests {
 
     #[wasm_bindgen_test]
     async fn button_to_vote_different_exists() {
-        render_yew_component!(ImagesToCompare);
+        render_yew_component!(ExperimentEqualOrDifferent);
         wasm_sleep_in_ms(150).await;
 
         assert!(DOM::get_button_by_id("vote_different").is_some());
@@ -629,13 +286,11 @@ mod tests {
 
     #[wasm_bindgen_test]
     async fn vote_same_button_text_is_visible() {
-        GET_IMAGES_RETURNS_OK.store(true, Ordering::SeqCst);
-        GET_USER_RETURNS_OK.store(true, Ordering::SeqCst);
         // add 1 to len to run even if no languages are available
         for language_index in 0..AVAILABLE_LANGUAGES.len() + 1 {
             DEFAULT_LANGUAGE.store(language_index, Ordering::SeqCst);
 
-            render_yew_component!(ImagesToCompare);
+            render_yew_component!(ExperimentEqualOrDifferent);
             wasm_sleep_in_ms(50).await;
 
             let language = Language::default();
@@ -651,13 +306,11 @@ mod tests {
 
     #[wasm_bindgen_test]
     async fn vote_different_button_text_is_visible() {
-        GET_IMAGES_RETURNS_OK.store(true, Ordering::SeqCst);
-        GET_USER_RETURNS_OK.store(true, Ordering::SeqCst);
         // add 1 to len to run even if no languages are available
         for language_index in 0..AVAILABLE_LANGUAGES.len() + 1 {
             DEFAULT_LANGUAGE.store(language_index, Ordering::SeqCst);
 
-            render_yew_component!(ImagesToCompare);
+            render_yew_component!(ExperimentEqualOrDifferent);
             wasm_sleep_in_ms(50).await;
 
             let language = Language::default();
@@ -673,10 +326,7 @@ mod tests {
 
     #[wasm_bindgen_test]
     async fn choosing_same_loads_new_images() {
-        GET_IMAGES_RETURNS_OK.store(true, Ordering::SeqCst);
-        GET_USER_RETURNS_OK.store(true, Ordering::SeqCst);
-
-        render_yew_component!(ImagesToCompare);
+        render_yew_component!(ExperimentEqualOrDifferent);
         wasm_sleep_in_ms(150).await;
 
         let button = DOM::get_button_by_id("vote_same")
@@ -689,14 +339,14 @@ mod tests {
         assert!(DOM::get_images_by_id_contains("image_to_compare_").is_none());
         assert_eq!(
             DOM::get_buttons_by_id_contains("loading_status_button_")
-                .unwrap_or(vec![])
+                .unwrap_or_default()
                 .len(),
             2
         );
         wasm_sleep_in_ms(100).await; // allow images to actually load
         assert_eq!(
             DOM::get_images_by_id_contains("image_to_compare")
-                .unwrap_or(vec![])
+                .unwrap_or_default()
                 .len(),
             2
         );
@@ -704,10 +354,7 @@ mod tests {
 
     #[wasm_bindgen_test]
     async fn choosing_different_loads_new_images() {
-        GET_IMAGES_RETURNS_OK.store(true, Ordering::SeqCst);
-        GET_USER_RETURNS_OK.store(true, Ordering::SeqCst);
-
-        render_yew_component!(ImagesToCompare);
+        render_yew_component!(ExperimentEqualOrDifferent);
         wasm_sleep_in_ms(150).await;
 
         let button = DOM::get_button_by_id("vote_different")
@@ -720,139 +367,76 @@ mod tests {
         assert!(DOM::get_images_by_id_contains("image_to_compare_").is_none());
         assert_eq!(
             DOM::get_buttons_by_id_contains("loading_status_button_")
-                .unwrap_or(vec![])
+                .unwrap_or_default()
                 .len(),
             2
         );
         wasm_sleep_in_ms(100).await; // allow images to actually load
         assert_eq!(
             DOM::get_images_by_id_contains("image_to_compare_")
-                .unwrap_or(vec![])
+                .unwrap_or_default()
                 .len(),
             2
         );
     }
 
     #[wasm_bindgen_test]
-    async fn when_user_has_0_votes_show_instructions_modal() {
-        GET_IMAGES_RETURNS_OK.store(true, Ordering::SeqCst);
-        GET_USER_RETURNS_OK.store(true, Ordering::SeqCst);
-        DOM::local_storage()
-            .unwrap()
-            .set_item("user_id", "123456")
-            .unwrap();
-        VOTES_TO_DISPLAY.store(0, Ordering::SeqCst);
-
-        render_yew_component!(ImagesToCompare);
-        wasm_sleep_in_ms(150).await;
-
-        assert!(DOM::get_element_by_id("instructions_modal").is_some());
-    }
+    async fn vote_records_presentation() {
+        let api = FakeClient::default();
 
-    #[wasm_bindgen_test]
-    async fn when_user_has_more_than_0_votes_do_not_show_instructions_modal() {
-        GET_IMAGES_RETURNS_OK.store(true, Ordering::SeqCst);
-        GET_USER_RETURNS_OK.store(true, Ordering::SeqCst);
-        DOM::local_storage()
-            .unwrap()
-            .set_item("user_id", "123456")
-            .unwrap();
-        VOTES_TO_DISPLAY.store(1, Ordering::SeqCst);
-
-        render_yew_component!(ImagesToCompare);
+        render_yew_component!(ExperimentEqualOrDifferent, api.clone());
         wasm_sleep_in_ms(150).await;
 
-        assert!(DOM::get_element_by_id("instructions_modal").is_none());
-    }
-
-    #[wasm_bindgen_test]
-    async fn button_to_show_instructions_modal_exists() {
-        render_yew_component!(ImagesToCompare);
-        wasm_sleep_in_ms(150).await;
-
-        assert!(
-            DOM::get_button_by_id("open_instructions_modal_button").is_some()
-        );
-    }
-
-    #[wasm_bindgen_test]
-    fn instructions_button_sr_markdown_exists() {
-        // add 1 to len to run even if no languages are available
-        for language_index in 0..AVAILABLE_LANGUAGES.len() + 1 {
-            let language = Language {
-                index: language_index,
-            };
-            let file = language.load_file("instructions_button_sr.md");
-
-            assert!(file.is_some())
-        }
-    }
-
-    #[wasm_bindgen_test]
-    async fn instructions_button_sr_text_is_rendered() {
-        // add 1 to len to run even if no languages are available
-        for language_index in 0..AVAILABLE_LANGUAGES.len() + 1 {
-            DEFAULT_LANGUAGE.store(language_index, Ordering::SeqCst);
-
-            render_yew_component!(ImagesToCompare);
-            wasm_sleep_in_ms(50).await;
-
-            let language = Language::default();
-            let expected = language.load_file("instructions_button_sr.md");
-            let expected = markdown_to_decoded_html(expected.unwrap_or(""));
-
-            let text = DOM::get_element_by_id("open_instructions_modal_button")
-                .expect("Element #open_instructions_modal_button to exist")
-                .last_element_child()
-                .expect("Last element child to exist");
-
-            assert_eq!(text.inner_html(), expected);
-        }
-    }
-
-    #[wasm_bindgen_test]
-    async fn button_to_show_instructions_modal_works() {
-        GET_IMAGES_RETURNS_OK.store(true, Ordering::SeqCst);
-        GET_USER_RETURNS_OK.store(true, Ordering::SeqCst);
-        DOM::local_storage()
-            .unwrap()
-            .set_item("user_id", "123456")
-            .unwrap();
-        VOTES_TO_DISPLAY.store(1, Ordering::SeqCst);
-
-        render_yew_component!(ImagesToCompare);
-        wasm_sleep_in_ms(150).await;
+        DOM::get_button_by_id("presentation_wipe_button")
+            .expect("Button to be present")
+            .dyn_into::<web_sys::HtmlElement>()
+            .expect("Element to be castable to HtmlElement")
+            .click();
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+        assert!(DOM::get_element_by_id("wipe").is_some());
 
-        let button = DOM::get_button_by_id("open_instructions_modal_button")
-            .expect("Element #open_instructions_modal_button to be present")
+        DOM::get_button_by_id("vote_same")
+            .expect("Button to be present")
             .dyn_into::<web_sys::HtmlElement>()
-            .expect("Element to be castable to HtmlElement");
+            .expect("Element to be castable to HtmlElement")
+            .click();
+        wasm_sleep_in_ms(50).await; // allow vote to be posted
 
-        button.click();
-        wasm_sleep_in_ms(50).await; // allow page to re-render
-        assert!(DOM::get_element_by_id("instructions_modal").is_some());
+        let votes = api.posted_votes();
+        assert_eq!(votes.len(), 1);
+        assert_eq!(votes[0].recorded_presentation(), Presentation::Wipe);
     }
 
     #[wasm_bindgen_test]
-    async fn instructions_modal_can_be_closed() {
-        GET_IMAGES_RETURNS_OK.store(true, Ordering::SeqCst);
-        GET_USER_RETURNS_OK.store(true, Ordering::SeqCst);
-        DOM::local_storage()
-            .unwrap()
-            .set_item("user_id", "123456")
-            .unwrap();
-        VOTES_TO_DISPLAY.store(0, Ordering::SeqCst);
-
-        render_yew_component!(ImagesToCompare);
+    async fn loupe_follows_the_pointer_over_every_image() {
+        render_yew_component!(ExperimentEqualOrDifferent);
         wasm_sleep_in_ms(150).await;
 
-        let button = DOM::get_button_by_id("close_instructions_modal_button")
-            .expect("Element #close_instructions_modal_button to be present")
+        DOM::get_button_by_id("loupe_button")
+            .expect("Button to be present")
             .dyn_into::<web_sys::HtmlElement>()
-            .expect("Element to be castable to HtmlElement");
+            .expect("Element to be castable to HtmlElement")
+            .click();
+        wasm_sleep_in_ms(50).await; // allow page to re-render
 
-        button.click();
+        let frame = DOM::get_element_by_id("image_to_compare_button_0")
+            .expect("Element #image_to_compare_button_0 to exist");
+        let rect = frame.get_bounding_client_rect();
+        let mut init = web_sys::MouseEventInit::new();
+        init.bubbles(true)
+            .client_x((rect.left() + rect.width() / 2.0) as i32)
+            .client_y((rect.top() + rect.height() / 2.0) as i32);
+        let event = web_sys::MouseEvent::new_with_mouse_event_init_dict(
+            "pointermove",
+            &init,
+        )
+        .expect("MouseEvent to be constructible");
+        frame
+            .dispatch_event(&event)
+            .expect("Event to be dispatched");
         wasm_sleep_in_ms(50).await; // allow page to re-render
-        assert!(DOM::get_element_by_id("instructions_modal").is_none());
+
+        assert!(DOM::get_element_by_id("loupe_0").is_some());
+        assert!(DOM::get_element_by_id("loupe_1").is_some());
     }
 }
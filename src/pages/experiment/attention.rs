@@ -0,0 +1,206 @@
+use std::{
+    cell::RefCell,
+    rc::Rc,
+};
+
+use gloo_events::EventListener;
+use yew::{
+    hook,
+    use_effect_with,
+    use_mut_ref,
+};
+
+use super::display_times::DisplayTimes;
+use crate::{
+    dom::DOM,
+    request::{
+        Comparison,
+        Interruptions,
+    },
+};
+
+/// Whether the participant left the comparison on screen, by hiding the
+/// page (e.g. switching tabs) or moving the focus out of the window, on the
+/// clock of [`DOM::now`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(super) struct Attention {
+    interruptions: usize,
+    hidden_ms: f64,
+    hidden_since: Option<f64>,
+    away: bool,
+}
+
+impl Attention {
+    fn hide(&mut self, at: f64) {
+        self.leave();
+        self.hidden_since.get_or_insert(at);
+    }
+
+    fn show(&mut self, at: f64) {
+        if let Some(since) = self.hidden_since.take() {
+            self.hidden_ms += at - since;
+        }
+    }
+
+    fn leave(&mut self) {
+        if !self.away {
+            self.away = true;
+            self.interruptions += 1;
+        }
+    }
+
+    /// Whether the participant was away.
+    fn come_back(&mut self) -> bool {
+        std::mem::replace(&mut self.away, false)
+    }
+
+    fn interruptions_at(&self, at: f64) -> Interruptions {
+        let hidden_now = self.hidden_since.map_or(0.0, |since| at - since);
+
+        Interruptions {
+            count: self.interruptions,
+            hidden_ms: self.hidden_ms + hidden_now,
+        }
+    }
+
+    /// Interruptions up to an answer given now.
+    pub(super) fn interruptions_now(&self) -> Option<Interruptions> {
+        Some(self.interruptions_at(DOM::now()?))
+    }
+}
+
+fn page_is_hidden() -> bool {
+    DOM::document().is_some_and(|document| document.hidden())
+}
+
+fn page_has_focus() -> bool {
+    DOM::document()
+        .and_then(|document| document.has_focus().ok())
+        .unwrap_or(true)
+}
+
+/// Tracks the [`Attention`] paid to each comparison shown, i.e. since
+/// `shown` last became `Some`, through `visibilitychange` and window
+/// `blur`/`focus` events. With `restart_timer`, the display time the
+/// response time is measured from is reset whenever the participant comes
+/// back.
+#[hook]
+pub(super) fn use_attention(
+    shown: Option<Comparison>,
+    display_times: Rc<RefCell<DisplayTimes>>,
+    restart_timer: bool,
+) -> Rc<RefCell<Attention>> {
+    let attention = use_mut_ref(Attention::default);
+
+    {
+        let attention = attention.clone();
+        use_effect_with(shown, move |_| {
+            let mut attention = attention.borrow_mut();
+            *attention = Attention::default();
+            if page_is_hidden() {
+                if let Some(now) = DOM::now() {
+                    attention.hide(now);
+                }
+            }
+        });
+    }
+
+    {
+        let attention = attention.clone();
+        use_effect_with((), move |_| {
+            let come_back = move |attention: &mut Attention, now: f64| {
+                if attention.come_back() && restart_timer {
+                    display_times.borrow_mut().restart(now);
+                }
+            };
+            let visibility_listener = DOM::document().map(|document| {
+                let attention = attention.clone();
+                let come_back = come_back.clone();
+                EventListener::new(&document, "visibilitychange", move |_| {
+                    let Some(now) = DOM::now() else {
+                        return;
+                    };
+                    let mut attention = attention.borrow_mut();
+                    if page_is_hidden() {
+                        attention.hide(now);
+                    } else {
+                        attention.show(now);
+                        if page_has_focus() {
+                            come_back(&mut attention, now);
+                        }
+                    }
+                })
+            });
+            let focus_listeners = DOM::window().map(|window| {
+                let blur = {
+                    let attention = attention.clone();
+                    EventListener::new(&window, "blur", move |_| {
+                        attention.borrow_mut().leave()
+                    })
+                };
+                let focus = EventListener::new(&window, "focus", move |_| {
+                    if let Some(now) = DOM::now() {
+                        come_back(&mut attention.borrow_mut(), now);
+                    }
+                });
+                (blur, focus)
+            });
+            move || drop((visibility_listener, focus_listeners))
+        });
+    }
+
+    attention
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+
+    use super::Attention;
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    fn switching_tabs_is_one_interruption() {
+        let mut attention = Attention::default();
+
+        // switching tabs blurs the window, then hides the page
+        attention.leave();
+        attention.hide(1000.0);
+        attention.show(4000.0);
+        assert!(attention.come_back());
+
+        let interruptions = attention.interruptions_at(5000.0);
+        assert_eq!(interruptions.count, 1);
+        assert_eq!(interruptions.hidden_ms, 3000.0);
+    }
+
+    #[wasm_bindgen_test]
+    fn time_hidden_counts_until_the_answer() {
+        let mut attention = Attention::default();
+
+        attention.hide(1000.0);
+        attention.show(1500.0);
+        attention.come_back();
+        attention.hide(2000.0);
+
+        let interruptions = attention.interruptions_at(2250.0);
+        assert_eq!(interruptions.count, 2);
+        assert_eq!(interruptions.hidden_ms, 750.0);
+    }
+
+    #[wasm_bindgen_test]
+    fn losing_focus_is_not_time_hidden() {
+        let mut attention = Attention::default();
+
+        attention.leave();
+        assert!(attention.come_back());
+        assert!(!attention.come_back());
+
+        let interruptions = attention.interruptions_at(2000.0);
+        assert_eq!(interruptions.count, 1);
+        assert_eq!(interruptions.hidden_ms, 0.0);
+    }
+}
@@ -0,0 +1,167 @@
+use yew::{
+    classes,
+    function_component,
+    html,
+    use_context,
+    Callback,
+    Html,
+    Properties,
+    UseReducerHandle,
+};
+
+use crate::{
+    assets::ExclamationTriangle,
+    pages::markdown_to_yew_html,
+    shared_components::{
+        Button,
+        Modal,
+    },
+    Language,
+};
+
+#[derive(Properties, PartialEq)]
+pub(super) struct CatchTrialWarningModalProps {
+    pub(super) onclose: Callback<()>,
+}
+
+/// Asks the participant to pay attention, after failed catch trials.
+#[function_component(CatchTrialWarningModal)]
+pub(super) fn catch_trial_warning_modal(
+    props: &CatchTrialWarningModalProps,
+) -> Html {
+    let language = match use_context::<UseReducerHandle<Language>>() {
+        Some(ctx) => (*ctx).clone(),
+        None => Language::default(),
+    };
+
+    let catch_trial_warning = language.load_file("catch_trial_warning.md");
+    let catch_trial_warning =
+        markdown_to_yew_html(catch_trial_warning.unwrap_or(""));
+
+    let catch_trial_warning_button =
+        language.load_file("catch_trial_warning_button.md");
+    let catch_trial_warning_button =
+        markdown_to_yew_html(catch_trial_warning_button.unwrap_or(""));
+
+    html! {
+        <Modal
+            id={"catch_trial_warning_modal"}
+            onclose={props.onclose.clone()}
+        >
+            <section
+                id={"catch_trial_warning_title"}
+                class={classes!["self-center"]}
+            >
+                <ExclamationTriangle
+                    class={classes![
+                        "h-16",
+                        "stroke-amber-600",
+                    ]}
+                />
+            </section>
+            <section
+                id={"catch_trial_warning_content"}
+                class={classes![
+                    "flex",
+                    "flex-col",
+                    "gap-4",
+                    "text-xl",
+                    "leading-normal",
+                    "text-center",
+                    "my-8",
+                ]}
+            >
+                { catch_trial_warning }
+            </section>
+            <section
+                class={classes![
+                    "pb-8",
+                    "self-center",
+                ]}
+            >
+                <Button
+                    id={"catch_trial_warning_button"}
+                    class={classes![
+                        "text-gray-600",
+                        "border-2",
+                        "border-gray-600",
+                    ]}
+                    onclick={props.onclose.clone()}
+                >
+                    { catch_trial_warning_button }
+                </Button>
+            </section>
+        </Modal>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::Ordering;
+
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+    use yew::{
+        function_component,
+        html,
+        Html,
+    };
+
+    use super::CatchTrialWarningModal;
+    use crate::{
+        dom::DOM,
+        markdown_to_decoded_html,
+        render_yew_component,
+        wasm_sleep_in_ms,
+        Language,
+        AVAILABLE_LANGUAGES,
+        DEFAULT_LANGUAGE,
+    };
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[function_component(TestCatchTrialWarningModal)]
+    fn test_catch_trial_warning_modal() -> Html {
+        html! {
+            <div>
+                <CatchTrialWarningModal onclose={|_| ()} />
+            </div>
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn catch_trial_warning_markdown_exists() {
+        // add 1 to len to run even if no languages are available
+        for language_index in 0..AVAILABLE_LANGUAGES.len() + 1 {
+            let language = Language {
+                index: language_index,
+            };
+
+            assert!(language.load_file("catch_trial_warning.md").is_some());
+            assert!(language
+                .load_file("catch_trial_warning_button.md")
+                .is_some());
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn catch_trial_warning_text_is_visible() {
+        // add 1 to len to run even if no languages are available
+        for language_index in 0..AVAILABLE_LANGUAGES.len() + 1 {
+            DEFAULT_LANGUAGE.store(language_index, Ordering::SeqCst);
+
+            render_yew_component!(TestCatchTrialWarningModal);
+            wasm_sleep_in_ms(50).await;
+
+            let language = Language::default();
+            let expected = language.load_file("catch_trial_warning.md");
+            let expected = markdown_to_decoded_html(expected.unwrap_or(""));
+
+            let text = DOM::get_element_by_id("catch_trial_warning_content")
+                .expect("Element #catch_trial_warning_content to exist");
+
+            assert_eq!(text.inner_html(), expected);
+        }
+    }
+}
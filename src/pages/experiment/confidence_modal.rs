@@ -0,0 +1,212 @@
+use std::ops::RangeInclusive;
+
+use yew::{
+    classes,
+    function_component,
+    html,
+    use_context,
+    Callback,
+    Html,
+    Properties,
+    UseReducerHandle,
+};
+
+use crate::{
+    pages::markdown_to_yew_html,
+    shared_components::{
+        Button,
+        Modal,
+    },
+    Language,
+};
+
+#[derive(Properties, PartialEq)]
+pub(super) struct ConfidenceModalProps {
+    /// Points of the scale, each labelled by `confidence_scale_{n}.md` when
+    /// it exists.
+    pub(super) scale: RangeInclusive<u8>,
+    pub(super) onselect: Callback<u8>,
+    /// Goes back to choosing, without voting.
+    pub(super) onclose: Callback<()>,
+}
+
+/// Asks how confident the participant is in the answer just chosen, before
+/// it is submitted.
+#[function_component(ConfidenceModal)]
+pub(super) fn confidence_modal(props: &ConfidenceModalProps) -> Html {
+    let language = match use_context::<UseReducerHandle<Language>>() {
+        Some(ctx) => (*ctx).clone(),
+        None => Language::default(),
+    };
+
+    let confidence_prompt = language.load_file("confidence_prompt.md");
+    let confidence_prompt =
+        markdown_to_yew_html(confidence_prompt.unwrap_or(""));
+
+    let cancel_action_button = language.load_file("cancel_action_button.md");
+    let cancel_action_button =
+        markdown_to_yew_html(cancel_action_button.unwrap_or(""));
+
+    let confidence_buttons = props
+        .scale
+        .clone()
+        .map(|confidence| {
+            let label = language
+                .load_file(&format!("confidence_scale_{confidence}.md"))
+                .map(markdown_to_yew_html)
+                .unwrap_or_else(|| html! { { confidence } });
+            let onclick = {
+                let onselect = props.onselect.clone();
+                Callback::from(move |_| onselect.emit(confidence))
+            };
+
+            html! {
+                <Button
+                    id={format!("confidence_button_{confidence}")}
+                    class={classes![
+                        "text-gray-600",
+                        "border-2",
+                        "border-gray-600",
+                        "w-full",
+                    ]}
+                    onclick={onclick}
+                >
+                    { label }
+                </Button>
+            }
+        })
+        .collect::<Html>();
+
+    html! {
+        <Modal
+            id={"confidence_modal"}
+            onclose={props.onclose.clone()}
+        >
+            <section
+                id={"confidence_prompt"}
+                class={classes![
+                    "text-xl",
+                    "leading-normal",
+                    "text-center",
+                    "my-8",
+                ]}
+            >
+                { confidence_prompt }
+            </section>
+            <section
+                id={"confidence_buttons"}
+                class={classes![
+                    "self-center",
+                    "flex",
+                    "flex-col",
+                    "md:flex-row",
+                    "w-4/5",
+                    "items-stretch",
+                    "gap-2",
+                    "md:gap-4",
+                ]}
+            >
+                { confidence_buttons }
+            </section>
+            <section
+                class={classes![
+                    "py-8",
+                    "self-center",
+                ]}
+            >
+                <Button
+                    id={"confidence_cancel_button"}
+                    class={classes![
+                        "text-gray-600",
+                        "border-2",
+                        "border-gray-600",
+                    ]}
+                    onclick={props.onclose.clone()}
+                >
+                    { cancel_action_button }
+                </Button>
+            </section>
+        </Modal>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::Ordering;
+
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+    use yew::{
+        function_component,
+        html,
+        Html,
+    };
+
+    use super::ConfidenceModal;
+    use crate::{
+        dom::DOM,
+        markdown_to_decoded_html,
+        render_yew_component,
+        wasm_sleep_in_ms,
+        Language,
+        AVAILABLE_LANGUAGES,
+        DEFAULT_LANGUAGE,
+    };
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[function_component(TestConfidenceModal)]
+    fn test_confidence_modal() -> Html {
+        html! {
+            <div>
+                <ConfidenceModal
+                    scale={1..=4}
+                    onselect={|_| ()}
+                    onclose={|_| ()}
+                />
+            </div>
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn confidence_markdown_exists() {
+        // add 1 to len to run even if no languages are available
+        for language_index in 0..AVAILABLE_LANGUAGES.len() + 1 {
+            let language = Language {
+                index: language_index,
+            };
+
+            assert!(language.load_file("confidence_prompt.md").is_some());
+            for confidence in 1..=3 {
+                let file = language
+                    .load_file(&format!("confidence_scale_{confidence}.md"));
+                assert!(file.is_some());
+            }
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn confidence_buttons_show_scale_labels() {
+        // add 1 to len to run even if no languages are available
+        for language_index in 0..AVAILABLE_LANGUAGES.len() + 1 {
+            DEFAULT_LANGUAGE.store(language_index, Ordering::SeqCst);
+
+            render_yew_component!(TestConfidenceModal);
+            wasm_sleep_in_ms(50).await;
+
+            let language = Language::default();
+            let expected = language.load_file("confidence_scale_1.md");
+            let expected = markdown_to_decoded_html(expected.unwrap_or(""));
+
+            let button = DOM::get_button_by_id("confidence_button_1")
+                .expect("Element #confidence_button_1 to exist");
+            assert_eq!(button.inner_html(), expected);
+
+            // points without a label show their number
+            let button = DOM::get_button_by_id("confidence_button_4")
+                .expect("Element #confidence_button_4 to exist");
+            assert_eq!(button.inner_html(), "4");
+        }
+    }
+}
@@ -0,0 +1,260 @@
+use std::{
+    cell::{
+        Cell,
+        RefCell,
+    },
+    rc::Rc,
+};
+
+use futures::future::join_all;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use yew::{
+    hook,
+    use_effect_with,
+    use_mut_ref,
+    use_state_eq,
+};
+
+use crate::{
+    dom::DOM,
+    request::{
+        Comparison,
+        Timing,
+    },
+};
+
+/// When the images of the comparison on screen were decoded and painted,
+/// on the clock of [`DOM::now`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(super) struct DisplayTimes {
+    decoded: Option<f64>,
+    painted: Option<f64>,
+    /// When the response time is measured from, if not the paint.
+    timer_start: Option<f64>,
+}
+
+/// Comparison whose display is settled: its images were painted at
+/// `painted`, or could not be decoded.
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct Displayed {
+    pub(super) id: String,
+    pub(super) painted: Option<f64>,
+}
+
+impl DisplayTimes {
+    fn answered_at(&self, answered: f64) -> Timing {
+        Timing {
+            decoded_ms: self.decoded,
+            painted_ms: self.painted,
+            answered_ms: answered,
+            response_time_ms: self
+                .timer_start
+                .or(self.painted)
+                .map(|start| answered - start),
+        }
+    }
+
+    /// Measures the response time from `at` rather than from the paint,
+    /// once painted.
+    pub(super) fn restart(&mut self, at: f64) {
+        if self.painted.is_some() {
+            self.timer_start = Some(at);
+        }
+    }
+
+    /// Timing of an answer given now.
+    pub(super) fn answered_now(&self) -> Option<Timing> {
+        Some(self.answered_at(DOM::now()?))
+    }
+}
+
+/// Resolves on the next animation frame.
+async fn next_frame() {
+    let promise = js_sys::Promise::new(&mut |resolve, _| {
+        if let Some(window) = DOM::window() {
+            let _ = window.request_animation_frame(&resolve);
+        }
+    });
+    let _ = JsFuture::from(promise).await;
+}
+
+/// Decodes the images rendered in `#images_list` all at once, so they are
+/// ready to be painted; `false` if one cannot be, or there are none.
+async fn decode_images() -> bool {
+    let Some(images) = DOM::get_element_by_id("images_list")
+        .map(|list| list.get_elements_by_tag_name("img"))
+    else {
+        return false;
+    };
+    let decoding = (0..images.length())
+        .filter_map(|index| images.item(index))
+        .filter_map(|image| image.dyn_into::<web_sys::HtmlImageElement>().ok())
+        .map(|image| JsFuture::from(image.decode()))
+        .collect::<Vec<_>>();
+
+    !decoding.is_empty() && join_all(decoding).await.iter().all(Result::is_ok)
+}
+
+/// Times the display of each comparison shown, i.e. each time `shown`
+/// becomes `Some`: when its images are decoded, then the end of the next
+/// frame, the first one they can have been painted in. Also gives the
+/// comparison once its display is settled.
+#[hook]
+pub(super) fn use_display_times(
+    shown: Option<Comparison>,
+) -> (Rc<RefCell<DisplayTimes>>, Option<Displayed>) {
+    let times = use_mut_ref(DisplayTimes::default);
+    let displayed = use_state_eq(|| None::<Displayed>);
+
+    {
+        let times = times.clone();
+        let displayed = displayed.clone();
+        use_effect_with(shown, move |shown| {
+            *times.borrow_mut() = DisplayTimes::default();
+            displayed.set(None);
+            let cancelled = Rc::new(Cell::new(false));
+            if let Some(comparison) = shown.clone() {
+                let cancelled = cancelled.clone();
+                wasm_bindgen_futures::spawn_local(async move {
+                    // let the stimuli render the comparison first
+                    next_frame().await;
+                    let decoded = decode_images().await;
+                    if cancelled.get() {
+                        return;
+                    }
+                    if decoded {
+                        times.borrow_mut().decoded = DOM::now();
+                        // the first frame callback runs before that frame
+                        // is painted, the second one after
+                        next_frame().await;
+                        next_frame().await;
+                        if cancelled.get() {
+                            return;
+                        }
+                        times.borrow_mut().painted = DOM::now();
+                    }
+                    displayed.set(Some(Displayed {
+                        id: comparison.id,
+                        painted: times.borrow().painted,
+                    }));
+                });
+            }
+            move || cancelled.set(true)
+        });
+    }
+
+    (times, (*displayed).clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+
+    use super::{
+        decode_images,
+        DisplayTimes,
+    };
+    use crate::dom::DOM;
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    /// 1x1 transparent GIF.
+    const PIXEL: &str = "data:image/gif;base64,R0lGODlhAQABAIAAAAAAAP///\
+                         yH5BAEAAAAALAAAAAABAAEAAAIBRAA7";
+
+    /// Whether the images of the sources decode, rendered in an
+    /// `#images_list` removed afterwards.
+    async fn with_images_list(sources: &[&str]) -> bool {
+        let document = DOM::document().expect("Document to exist");
+        let list = document
+            .create_element("section")
+            .expect("Element to be created");
+        list.set_id("images_list");
+        for source in sources {
+            let image = document
+                .create_element("img")
+                .expect("Element to be created");
+            image
+                .set_attribute("src", source)
+                .expect("Attribute to be set");
+            list.append_child(&image).expect("Image to be appended");
+        }
+        document
+            .body()
+            .expect("Body to exist")
+            .append_child(&list)
+            .expect("List to be appended");
+
+        let decoded = decode_images().await;
+        list.remove();
+        decoded
+    }
+
+    #[wasm_bindgen_test]
+    fn response_time_runs_from_paint_to_answer() {
+        let times = DisplayTimes {
+            decoded: Some(1000.0),
+            painted: Some(1020.0),
+            timer_start: None,
+        };
+
+        let timing = times.answered_at(2500.0);
+
+        assert_eq!(timing.decoded_ms, Some(1000.0));
+        assert_eq!(timing.painted_ms, Some(1020.0));
+        assert_eq!(timing.answered_ms, 2500.0);
+        assert_eq!(timing.response_time_ms, Some(1480.0));
+    }
+
+    #[wasm_bindgen_test]
+    fn restarted_response_time_runs_from_the_restart() {
+        let mut times = DisplayTimes {
+            decoded: Some(1000.0),
+            painted: Some(1020.0),
+            timer_start: None,
+        };
+
+        times.restart(2000.0);
+        let timing = times.answered_at(2500.0);
+
+        assert_eq!(timing.painted_ms, Some(1020.0));
+        assert_eq!(timing.response_time_ms, Some(500.0));
+    }
+
+    #[wasm_bindgen_test]
+    fn answer_before_paint_has_no_response_time() {
+        let timing = DisplayTimes::default().answered_at(2500.0);
+
+        assert_eq!(timing.painted_ms, None);
+        assert_eq!(timing.response_time_ms, None);
+    }
+
+    #[wasm_bindgen_test]
+    async fn rendered_images_are_decoded() {
+        assert!(with_images_list(&[PIXEL, PIXEL]).await);
+    }
+
+    #[wasm_bindgen_test]
+    async fn one_broken_image_fails_the_decoding() {
+        assert!(!with_images_list(&[PIXEL, "/missing.png"]).await);
+    }
+
+    #[wasm_bindgen_test]
+    async fn nothing_rendered_is_not_decoded() {
+        assert!(!with_images_list(&[]).await);
+    }
+
+    #[wasm_bindgen_test]
+    fn restart_before_paint_is_ignored() {
+        let mut times = DisplayTimes::default();
+
+        times.restart(2000.0);
+        times.painted = Some(2100.0);
+        let timing = times.answered_at(2500.0);
+
+        assert_eq!(timing.response_time_ms, Some(400.0));
+    }
+}
@@ -0,0 +1,153 @@
+use std::{
+    cell::Cell,
+    rc::Rc,
+    time::Duration,
+};
+
+use yew::{
+    hook,
+    platform::time::sleep,
+    use_effect_with,
+    use_state_eq,
+};
+
+use super::display_times::Displayed;
+use crate::dom::DOM;
+
+/// Whether the comparison `shown` is still within its minimum viewing
+/// time, which runs from the frame its images were painted in, as the
+/// exposure does. It already is on the first render with `shown`, so no
+/// answer gets through before the timer starts.
+#[hook]
+pub(super) fn use_dwell(
+    duration: Option<Duration>,
+    shown: Option<String>,
+    displayed: Option<Displayed>,
+) -> bool {
+    // last comparison whose minimum viewing time is over
+    let dwelled = use_state_eq(|| None::<String>);
+
+    {
+        let dwelled = dwelled.clone();
+        use_effect_with(
+            (shown.clone(), displayed),
+            move |(shown, displayed)| {
+                let cancelled = Rc::new(Cell::new(false));
+                match (duration, displayed) {
+                    (Some(duration), Some(displayed))
+                        if shown.as_ref() == Some(&displayed.id) =>
+                    {
+                        let since_paint = displayed
+                            .painted
+                            .zip(DOM::now())
+                            .map(|(painted, now)| {
+                                Duration::from_secs_f64(
+                                    (now - painted).max(0.0) / 1000.0,
+                                )
+                            })
+                            .unwrap_or_default();
+                        let id = displayed.id.clone();
+                        let cancelled = cancelled.clone();
+                        wasm_bindgen_futures::spawn_local(async move {
+                            sleep(duration.saturating_sub(since_paint)).await;
+                            if !cancelled.get() {
+                                dwelled.set(Some(id));
+                            }
+                        });
+                    },
+                    _ => {},
+                }
+                move || cancelled.set(true)
+            },
+        );
+    }
+
+    duration.is_some() && shown.is_some() && *dwelled != shown
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+    use yew::{
+        function_component,
+        html,
+        use_state,
+        Html,
+        Properties,
+    };
+
+    use super::{
+        use_dwell,
+        Displayed,
+    };
+    use crate::{
+        dom::DOM,
+        render_yew_component,
+        shared_components::DwellProgress,
+        wasm_sleep_in_ms,
+    };
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[derive(Properties, PartialEq)]
+    struct TestDwellProps {
+        painted: bool,
+    }
+
+    #[function_component(TestDwell)]
+    fn test_dwell(props: &TestDwellProps) -> Html {
+        let duration = Duration::from_millis(200);
+        let painted = props.painted;
+        let displayed = use_state(|| {
+            painted.then(|| Displayed {
+                id: "a".to_string(),
+                painted: DOM::now(),
+            })
+        });
+        let dwelling = use_dwell(
+            Some(duration),
+            Some("a".to_string()),
+            (*displayed).clone(),
+        );
+
+        html! {
+            <div>
+                if dwelling {
+                    <DwellProgress duration={duration} />
+                }
+            </div>
+        }
+    }
+
+    #[function_component(PaintedDwell)]
+    fn painted_dwell() -> Html {
+        html! { <TestDwell painted=true /> }
+    }
+
+    #[function_component(UnpaintedDwell)]
+    fn unpainted_dwell() -> Html {
+        html! { <TestDwell painted=false /> }
+    }
+
+    #[wasm_bindgen_test]
+    async fn progress_is_shown_for_the_minimum_viewing_time() {
+        render_yew_component!(PaintedDwell);
+        wasm_sleep_in_ms(50).await;
+        assert!(DOM::get_element_by_id("dwell_progress").is_some());
+
+        wasm_sleep_in_ms(250).await;
+        assert!(DOM::get_element_by_id("dwell_progress").is_none());
+    }
+
+    #[wasm_bindgen_test]
+    async fn minimum_viewing_time_waits_for_the_paint() {
+        render_yew_component!(UnpaintedDwell);
+        wasm_sleep_in_ms(300).await;
+
+        assert!(DOM::get_element_by_id("dwell_progress").is_some());
+    }
+}
@@ -0,0 +1,237 @@
+use std::{
+    cell::{
+        Cell,
+        RefCell,
+    },
+    rc::Rc,
+    time::Duration,
+};
+
+use wasm_bindgen::{
+    closure::Closure,
+    JsCast,
+};
+use yew::{
+    classes,
+    function_component,
+    hook,
+    html,
+    use_effect_with,
+    use_state_eq,
+    Html,
+    Properties,
+};
+
+use super::display_times::Displayed;
+use crate::dom::DOM;
+
+/// Limited exposure: each comparison is shown for `duration`, then hidden
+/// behind a mask before the participant can answer.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct Exposure {
+    pub(crate) duration: Duration,
+    /// Whether the images are replaced by a checkerboard pattern rather
+    /// than a blank screen.
+    pub(crate) pattern: bool,
+}
+
+/// Where the comparison on screen is in its [`Exposure`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) enum ExposurePhase {
+    /// Shown until answered, as without [`Exposure`].
+    #[default]
+    Unlimited,
+    /// Shown for the exposure, answers are not accepted yet.
+    Exposed,
+    /// Hidden, waiting for the answer.
+    Masked { pattern: bool },
+}
+
+/// Exposure of the comparison on screen, and how long it was actually shown
+/// (in milliseconds) once masked.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(super) struct ExposureState {
+    pub(super) phase: ExposurePhase,
+    pub(super) measured: Option<f64>,
+}
+
+/// Calls `on_elapsed` with the time between `start` (by default, the first
+/// animation frame) and the first frame at least `duration` later, i.e. how
+/// long the frames painted in between were on screen. Setting the returned
+/// flag stops it.
+fn time_exposure(
+    duration: Duration,
+    start: Option<f64>,
+    on_elapsed: impl FnOnce(f64) + 'static,
+) -> Rc<Cell<bool>> {
+    let cancelled = Rc::new(Cell::new(false));
+    let Some(window) = DOM::window() else {
+        return cancelled;
+    };
+    let duration = duration.as_secs_f64() * 1000.0;
+    let frame = Rc::new(RefCell::new(None::<Closure<dyn FnMut(f64)>>));
+    let mut on_elapsed = Some(on_elapsed);
+    let mut start = start;
+
+    {
+        let next_frame = frame.clone();
+        let window = window.clone();
+        let cancelled = cancelled.clone();
+        *frame.borrow_mut() = Some(Closure::new(move |timestamp: f64| {
+            let start = *start.get_or_insert(timestamp);
+            let elapsed = timestamp - start;
+            if cancelled.get() || elapsed >= duration {
+                if let Some(on_elapsed) =
+                    on_elapsed.take().filter(|_| !cancelled.get())
+                {
+                    on_elapsed(elapsed);
+                }
+                // freed once this call returns
+                next_frame.borrow_mut().take();
+                return;
+            }
+            if let Some(callback) = next_frame.borrow().as_ref() {
+                let _ = window
+                    .request_animation_frame(callback.as_ref().unchecked_ref());
+            }
+        }));
+    }
+    if let Some(callback) = frame.borrow().as_ref() {
+        let _ =
+            window.request_animation_frame(callback.as_ref().unchecked_ref());
+    }
+
+    cancelled
+}
+
+/// Runs the [`Exposure`] of each comparison shown, i.e. each time `shown`
+/// becomes `Some` with the comparison's id: answers wait from then on, and
+/// the exposure is timed from the paint once `displayed` is that
+/// comparison.
+#[hook]
+pub(super) fn use_exposure(
+    exposure: Option<Exposure>,
+    shown: Option<String>,
+    displayed: Option<Displayed>,
+) -> ExposureState {
+    let state = use_state_eq(ExposureState::default);
+
+    {
+        let state = state.clone();
+        use_effect_with(shown.clone(), move |shown| {
+            state.set(match (exposure, shown) {
+                (Some(_), Some(_)) => ExposureState {
+                    phase: ExposurePhase::Exposed,
+                    measured: None,
+                },
+                _ => ExposureState::default(),
+            });
+        });
+    }
+
+    {
+        let state = state.clone();
+        use_effect_with((shown, displayed), move |(shown, displayed)| {
+            let cancelled = match (exposure, displayed) {
+                (Some(exposure), Some(displayed))
+                    if shown.as_ref() == Some(&displayed.id) =>
+                {
+                    let state = state.clone();
+                    Some(time_exposure(
+                        exposure.duration,
+                        displayed.painted,
+                        move |elapsed| {
+                            state.set(ExposureState {
+                                phase: ExposurePhase::Masked {
+                                    pattern: exposure.pattern,
+                                },
+                                measured: Some(elapsed),
+                            })
+                        },
+                    ))
+                },
+                _ => None,
+            };
+            move || {
+                if let Some(cancelled) = cancelled {
+                    cancelled.set(true);
+                }
+            }
+        });
+    }
+
+    *state
+}
+
+#[derive(Properties, PartialEq)]
+pub(crate) struct ExposureMaskProps {
+    pub(crate) phase: ExposurePhase,
+}
+
+/// Covers the images (the stimuli section it is put in, which must be
+/// `relative`) once their exposure is over. Clicks go through to what is
+/// under it.
+#[function_component(ExposureMask)]
+pub(crate) fn exposure_mask(props: &ExposureMaskProps) -> Html {
+    let ExposurePhase::Masked { pattern } = props.phase else {
+        return html! {};
+    };
+    let style = match pattern {
+        true => {
+            "background-image: repeating-conic-gradient(#000 0 25%, #fff 0 \
+             50%); background-size: 16px 16px;"
+        },
+        false => "",
+    };
+
+    html! {
+        <div
+            id="exposure_mask"
+            class={classes![
+                "absolute",
+                "inset-0",
+                "bg-gray-500",
+                "pointer-events-none",
+            ]}
+            style={style}
+        />
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::Cell,
+        rc::Rc,
+        time::Duration,
+    };
+
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+
+    use super::time_exposure;
+    use crate::{
+        dom::DOM,
+        wasm_sleep_in_ms,
+    };
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    async fn exposure_is_timed_from_the_paint() {
+        let now = DOM::now().expect("Clock to exist");
+        let elapsed = Rc::new(Cell::new(None));
+
+        // painted 150 ms ago, so a 200 ms exposure is nearly over
+        let _cancelled =
+            time_exposure(Duration::from_millis(200), Some(now - 150.0), {
+                let elapsed = elapsed.clone();
+                move |time| elapsed.set(Some(time))
+            });
+        wasm_sleep_in_ms(120).await;
+
+        let elapsed = elapsed.get().expect("Exposure to be over");
+        assert!(elapsed >= 200.0);
+    }
+}
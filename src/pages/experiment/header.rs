@@ -15,6 +15,7 @@ use super::{
     finish_comparing_modal::FinishComparingModal,
 };
 use crate::{
+    assets::ArrowPath,
     pages::markdown_to_yew_html,
     request::User,
     shared_components::Button,
@@ -25,6 +26,9 @@ use crate::{
 pub(super) struct HeaderProps {
     pub(super) user: User,
     pub(super) onreload: Callback<()>,
+    /// Votes waiting in the offline queue; an indicator shows while any are.
+    #[prop_or_default]
+    pub(super) pending_votes: usize,
 }
 
 #[function_component(Header)]
@@ -68,6 +72,14 @@ pub(super) fn header(props: &HeaderProps) -> Html {
     let finish_comparing_button =
         markdown_to_yew_html(&finish_comparing_button);
 
+    let pending_votes_indicator =
+        language.load_file("pending_votes_indicator.md");
+    let pending_votes_indicator = pending_votes_indicator
+        .unwrap_or("")
+        .replace("{votes}", &props.pending_votes.to_string());
+    let pending_votes_indicator =
+        markdown_to_yew_html(&pending_votes_indicator);
+
     html! {
         <section
             id="header"
@@ -91,6 +103,28 @@ pub(super) fn header(props: &HeaderProps) -> Html {
             >
                 { finish_comparing_button }
             </Button>
+            if props.pending_votes > 0 {
+                <div
+                    id="pending_votes_indicator"
+                    role="status"
+                    class={classes![
+                        "flex",
+                        "flex-row",
+                        "items-center",
+                        "gap-2",
+                        "text-gray-600",
+                    ]}
+                >
+                    <ArrowPath
+                        class={classes![
+                            "h-6",
+                            "animate-spin",
+                            "stroke-gray-600",
+                        ]}
+                    />
+                    <span>{ pending_votes_indicator }</span>
+                </div>
+            }
             <Button
                 id={"change_user_button"}
                 onclick={open_change_user_modal}
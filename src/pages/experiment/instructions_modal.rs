@@ -45,7 +45,6 @@ pub(super) fn instructions_modal(props: &InstructionsModalProps) -> Html {
 
     {
         let number_of_cards = number_of_cards.clone();
-        let get_number_of_cards = get_number_of_cards.clone();
 
         use_effect(move || {
             let n = get_number_of_cards();
@@ -58,15 +57,13 @@ pub(super) fn instructions_modal(props: &InstructionsModalProps) -> Html {
         let number_of_cards = *number_of_cards;
 
         Callback::from(move |_| {
-            match DOM::get_element_by_id("instructions_cards") {
-                Some(element) => {
-                    let card_length =
-                        element.scroll_width() / number_of_cards as i32;
-                    let index = (element.scroll_left() + (card_length / 2))
-                        / card_length;
-                    currently_visible_card.set(index as u32);
-                },
-                None => (),
+            if let Some(element) = DOM::get_element_by_id("instructions_cards")
+            {
+                let card_length =
+                    element.scroll_width() / number_of_cards as i32;
+                let index =
+                    (element.scroll_left() + (card_length / 2)) / card_length;
+                currently_visible_card.set(index as u32);
             }
         })
     };
@@ -76,15 +73,13 @@ pub(super) fn instructions_modal(props: &InstructionsModalProps) -> Html {
         let number_of_cards = *number_of_cards as i32;
 
         Callback::from(move |_| {
-            match DOM::get_element_by_id("instructions_cards") {
-                Some(element) => {
-                    let card_length = element.scroll_width() / number_of_cards;
-                    let modifier = index as i32 - currently_visible_card;
-                    let scroll_amount =
-                        element.scroll_left() + (card_length * modifier);
-                    element.set_scroll_left(scroll_amount);
-                },
-                None => (),
+            if let Some(element) = DOM::get_element_by_id("instructions_cards")
+            {
+                let card_length = element.scroll_width() / number_of_cards;
+                let modifier = index as i32 - currently_visible_card;
+                let scroll_amount =
+                    element.scroll_left() + (card_length * modifier);
+                element.set_scroll_left(scroll_amount);
             }
         })
     };
@@ -0,0 +1,1491 @@
+mod attention;
+mod catch_trial_warning_modal;
+mod change_user_modal;
+mod confidence_modal;
+mod display_times;
+mod dot_button;
+mod dwell;
+mod exposure;
+mod finish_comparing_modal;
+mod header;
+mod instructions_card;
+mod instructions_modal;
+mod pending_votes;
+mod prefetch;
+mod presentation_select;
+mod prompt;
+
+use std::{
+    ops::RangeInclusive,
+    time::Duration,
+};
+
+use yew::{
+    classes,
+    function_component,
+    html,
+    use_context,
+    use_effect_with,
+    use_mut_ref,
+    use_state_eq,
+    AttrValue,
+    BaseComponent,
+    Callback,
+    Html,
+    Properties,
+    UseReducerHandle,
+};
+
+pub(crate) use self::exposure::{
+    Exposure,
+    ExposureMask,
+    ExposurePhase,
+};
+use self::{
+    attention::use_attention,
+    catch_trial_warning_modal::CatchTrialWarningModal,
+    confidence_modal::ConfidenceModal,
+    display_times::use_display_times,
+    dwell::use_dwell,
+    exposure::use_exposure,
+    header::Header,
+    instructions_modal::InstructionsModal,
+    pending_votes::use_pending_votes,
+    prefetch::{
+        next_comparison,
+        prefetch_comparisons,
+        PrefetchBuffer,
+    },
+    presentation_select::PresentationSelect,
+    prompt::Prompt,
+};
+use crate::{
+    assets::QuestionMarkCircle,
+    dom::console_error,
+    pages::markdown_to_yew_html,
+    request::{
+        get_user,
+        session::Session,
+        use_api,
+        vote_queue::{
+            dequeue_vote,
+            enqueue_vote,
+            pending_votes,
+            send_vote,
+        },
+        Comparison,
+        Interruptions,
+        Presentation,
+        RequestError,
+        Timing,
+        User,
+        Viewport,
+        Vote,
+        VoteValue,
+    },
+    shared_components::{
+        Button,
+        DwellProgress,
+        FatalErrorModal,
+        Footer,
+    },
+    Language,
+};
+
+/// Points of the rating scale unless the experiment sets its own.
+const DEFAULT_RATING_SCALE: RangeInclusive<u8> = 1..=5;
+
+/// An experiment paradigm run inside [`ExperimentPage`].
+///
+/// The page takes care of the user, header, modals, footer and the
+/// fetch/vote loop; an experiment only supplies its prompt and the
+/// component laying out the stimuli, which produces the [`VoteValue`].
+pub(crate) trait Experiment: 'static {
+    /// Name of the markdown file (without extension) holding the prompt,
+    /// also used as the id of the prompt section.
+    const PROMPT: &'static str;
+
+    /// Component rendering the images of the current comparison and
+    /// emitting the participant's answer through `onvote`.
+    type Stimuli: BaseComponent<Properties = StimuliProps>;
+
+    /// How many comparisons to fetch ahead of the one being shown.
+    const PREFETCH: usize = 1;
+
+    /// Presentations the participant can switch between, the first one
+    /// being shown by default. The stimuli lay the images out as told by
+    /// their `presentation` prop.
+    const PRESENTATIONS: &'static [Presentation] = &[];
+
+    /// How long each image is shown in [`Presentation::Flicker`]; `None`
+    /// switches images only when the participant asks to.
+    const FLICKER_INTERVAL: Option<Duration> = Some(Duration::from_millis(500));
+
+    /// Magnification of the loupe the participant can turn on over the
+    /// images; `None` for stimuli without one.
+    const LOUPE_MAGNIFICATION: Option<f64> = None;
+
+    /// Shows each comparison for a limited time, then masks it until the
+    /// participant answers. Stimuli supporting it put an
+    /// [`ExposureMask`] over their images and keep their answers disabled
+    /// while [`StimuliProps::answer_disabled`].
+    const EXPOSURE: Option<Exposure> = None;
+
+    /// Points of the scale for stimuli rating images, each labelled by
+    /// `rating_scale_{n}.md` when there is one and by its number otherwise.
+    const RATING_SCALE: RangeInclusive<u8> = DEFAULT_RATING_SCALE;
+
+    /// Asks the participant, once an answer is chosen, how confident they
+    /// are in it on this scale before submitting the vote; `None` submits
+    /// it right away.
+    const CONFIDENCE_SCALE: Option<RangeInclusive<u8>> = None;
+
+    /// Failed catch trials after which the participant is warned to pay
+    /// attention, and warned again after as many more.
+    const CATCH_TRIAL_FAILURES_BEFORE_WARNING: usize = 2;
+
+    /// Measures the response time from when the participant comes back to
+    /// the page (after hiding it or leaving the window) rather than from
+    /// when the comparison was first displayed.
+    const RESTART_TIMER_ON_RETURN: bool = false;
+
+    /// How long each comparison is shown, with a progress bar, before
+    /// answers are accepted; `None` accepts them right away. Stimuli keep
+    /// their answers disabled while [`StimuliProps::answer_disabled`].
+    const MIN_VIEWING_TIME: Option<Duration> = None;
+}
+
+#[derive(Properties, PartialEq, Default)]
+pub(crate) struct ExperimentPageProps {
+    /// Directory of the stimulus set to request comparisons from.
+    pub(crate) dirname: AttrValue,
+}
+
+#[derive(Properties, PartialEq)]
+pub(crate) struct StimuliProps {
+    pub(crate) loading: bool,
+    pub(crate) images: Vec<String>,
+    pub(crate) onvote: Callback<VoteValue>,
+    #[prop_or_default]
+    pub(crate) presentation: Presentation,
+    #[prop_or_default]
+    pub(crate) flicker_interval: Option<Duration>,
+    #[prop_or_default]
+    pub(crate) loupe_magnification: Option<f64>,
+    /// For stimuli that can be zoomed, reports the part of the images on
+    /// screen, sent along with the vote.
+    #[prop_or_default]
+    pub(crate) onviewport: Callback<Viewport>,
+    /// Pristine image to show apart from `images`, labelled as such, for
+    /// double-stimulus trials.
+    #[prop_or_default]
+    pub(crate) reference: Option<String>,
+    #[prop_or_default]
+    pub(crate) exposure: ExposurePhase,
+    #[prop_or(DEFAULT_RATING_SCALE)]
+    pub(crate) rating_scale: RangeInclusive<u8>,
+    /// Whether the comparison is still within its minimum viewing time.
+    #[prop_or_default]
+    pub(crate) dwelling: bool,
+}
+
+impl StimuliProps {
+    /// Whether answers are not accepted yet, i.e. the comparison is loading,
+    /// still within its exposure or its minimum viewing time.
+    pub(crate) fn answer_disabled(&self) -> bool {
+        self.loading || self.exposure == ExposurePhase::Exposed || self.dwelling
+    }
+}
+
+/// Answer chosen by the participant, when, and how attentively.
+#[derive(Clone, PartialEq)]
+struct Answer {
+    vote_value: VoteValue,
+    timing: Option<Timing>,
+    interruptions: Option<Interruptions>,
+    /// Read as the answer is chosen, with the comparison still on screen.
+    session: Option<Session>,
+}
+
+#[function_component(ExperimentPage)]
+pub(crate) fn experiment_page<E: Experiment>(
+    props: &ExperimentPageProps,
+) -> Html {
+    let language = match use_context::<UseReducerHandle<Language>>() {
+        Some(ctx) => (*ctx).clone(),
+        None => Language::default(),
+    };
+    let loading = use_state_eq(|| true);
+    let request_error = use_state_eq(|| None::<RequestError>);
+    let fetch_attempt = use_state_eq(|| 0_usize);
+    let show_instructions_modal = use_state_eq(|| false);
+    let comparison_state = use_state_eq(|| None::<Comparison>);
+    let user_state = use_state_eq(User::default);
+    let api = use_api();
+    let pending_votes_state = use_pending_votes();
+    let prefetch = use_mut_ref(|| PrefetchBuffer::new(E::PREFETCH));
+    let viewport = use_mut_ref(|| None::<Viewport>);
+    // answer chosen, waiting for the participant's confidence in it
+    let pending_vote = use_state_eq(|| None::<Answer>);
+    let catch_trial_failures = use_mut_ref(|| 0_usize);
+    let show_catch_trial_warning = use_state_eq(|| false);
+    let presentation =
+        use_state_eq(|| E::PRESENTATIONS.first().copied().unwrap_or_default());
+    let shown_id = comparison_state
+        .as_ref()
+        .filter(|_| !*loading)
+        .map(|comparison| comparison.id.clone());
+    let shown_comparison = (*comparison_state).clone().filter(|_| !*loading);
+    let (display_times, displayed) =
+        use_display_times(shown_comparison.clone());
+    let painted = displayed
+        .as_ref()
+        .is_some_and(|displayed| shown_id.as_ref() == Some(&displayed.id));
+    let exposure =
+        use_exposure(E::EXPOSURE, shown_id.clone(), displayed.clone());
+    let dwelling = use_dwell(E::MIN_VIEWING_TIME, shown_id.clone(), displayed);
+    let attention = use_attention(
+        shown_comparison,
+        display_times.clone(),
+        E::RESTART_TIMER_ON_RETURN,
+    );
+
+    let instructions_button_sr =
+        language.load_file("instructions_button_sr.md");
+    let instructions_button_sr =
+        markdown_to_yew_html(instructions_button_sr.unwrap_or(""));
+
+    let reload = {
+        let loading = loading.clone();
+        let comparison_state = comparison_state.clone();
+        let prefetch = prefetch.clone();
+        let pending_vote = pending_vote.clone();
+        Callback::from(move |_| {
+            loading.set(true);
+            pending_vote.set(None);
+            comparison_state.set(None);
+            prefetch.borrow_mut().clear();
+        })
+    };
+
+    {
+        let reload = reload.clone();
+        use_effect_with(props.dirname.clone(), move |_| reload.emit(()));
+    }
+
+    let close_fatal_error_modal = {
+        let request_error = request_error.clone();
+        Callback::from(move |_| {
+            request_error.set(None);
+        })
+    };
+
+    let retry = {
+        let loading = loading.clone();
+        let request_error = request_error.clone();
+        let comparison_state = comparison_state.clone();
+        let fetch_attempt = fetch_attempt.clone();
+        Callback::from(move |_| {
+            loading.set(true);
+            request_error.set(None);
+            comparison_state.set(None);
+            fetch_attempt.set(*fetch_attempt + 1);
+        })
+    };
+
+    let open_instructions_modal = {
+        let show_instructions_modal = show_instructions_modal.clone();
+        Callback::from(move |_| {
+            show_instructions_modal.set(true);
+        })
+    };
+
+    let close_instructions_modal = {
+        let show_instructions_modal = show_instructions_modal.clone();
+        Callback::from(move |_| {
+            show_instructions_modal.set(false);
+        })
+    };
+
+    let close_catch_trial_warning = {
+        let show_catch_trial_warning = show_catch_trial_warning.clone();
+        Callback::from(move |_| show_catch_trial_warning.set(false))
+    };
+
+    let on_viewport = {
+        let viewport = viewport.clone();
+        Callback::from(move |new_viewport: Viewport| {
+            *viewport.borrow_mut() = Some(new_viewport);
+        })
+    };
+
+    let on_presentation_select = {
+        let presentation = presentation.clone();
+        Callback::from(move |new_presentation| {
+            presentation.set(new_presentation)
+        })
+    };
+
+    let submit_vote = {
+        let api = api.clone();
+        let loading = loading.clone();
+        let request_error = request_error.clone();
+        let comparison_state = comparison_state.clone();
+        let user_state = user_state.clone();
+        let pending_votes_state = pending_votes_state.clone();
+        let viewport = viewport.clone();
+        let presentation = *presentation;
+        let catch_trial_failures = catch_trial_failures.clone();
+        let show_catch_trial_warning = show_catch_trial_warning.clone();
+
+        Callback::from(move |(answer, confidence): (Answer, Option<u8>)| {
+            let Answer {
+                vote_value,
+                timing,
+                interruptions,
+                session,
+            } = answer;
+            let comparison = (*comparison_state)
+                .clone()
+                .expect("BUG: Comparison expected");
+            let catch_trial_passed = comparison
+                .catch_trial
+                .as_ref()
+                .and_then(|catch_trial| catch_trial.passed_by(&vote_value));
+            loading.set(true);
+            let api = api.clone();
+            let request_error = request_error.clone();
+            let comparison_state = comparison_state.clone();
+            let user_state = user_state.clone();
+            let pending_votes_state = pending_votes_state.clone();
+            let viewport = viewport.borrow().clone();
+            let catch_trial_failures = catch_trial_failures.clone();
+            let show_catch_trial_warning = show_catch_trial_warning.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                let vote = Vote::build(comparison)
+                    .user(user_state.id.clone())
+                    .vote(vote_value)
+                    .presentation(presentation)
+                    .viewport(viewport)
+                    .exposure(exposure.measured)
+                    .confidence(confidence)
+                    .catch_trial(catch_trial_passed)
+                    .timing(timing)
+                    .interruptions(interruptions)
+                    .session(session);
+                let idempotency_key = vote.idempotency_key().to_string();
+                // store the vote first, so it is not lost if sending fails
+                let queued = match enqueue_vote(&vote) {
+                    Ok(_) => true,
+                    Err(error) => {
+                        console_error!(error.to_string());
+                        false
+                    },
+                };
+                let response = send_vote(&api, vote).await;
+                let accepted = match response {
+                    Ok(_) => {
+                        dequeue_vote(&idempotency_key);
+                        comparison_state.set(None);
+                        true
+                    },
+                    // keep it for the background replay and move on
+                    Err(error) if queued && error.is_retryable() => {
+                        console_error!(error.to_string());
+                        comparison_state.set(None);
+                        true
+                    },
+                    Err(error) => {
+                        dequeue_vote(&idempotency_key);
+                        console_error!(error.to_string());
+                        request_error.set(Some(error));
+                        false
+                    },
+                };
+                // a vote that was not taken is answered again, so it is
+                // only counted once it is
+                if accepted && catch_trial_passed == Some(false) {
+                    let mut failures = catch_trial_failures.borrow_mut();
+                    *failures += 1;
+                    let warn_every = E::CATCH_TRIAL_FAILURES_BEFORE_WARNING;
+                    if *failures % warn_every.max(1) == 0 {
+                        show_catch_trial_warning.set(true);
+                    }
+                }
+                pending_votes_state.set(pending_votes().len());
+            });
+        })
+    };
+
+    let on_vote = {
+        let comparison_state = comparison_state.clone();
+        let pending_vote = pending_vote.clone();
+        let submit_vote = submit_vote.clone();
+
+        Callback::from(move |vote_value: VoteValue| {
+            if exposure.phase == ExposurePhase::Exposed || dwelling {
+                return;
+            }
+            let Some(comparison) = comparison_state.as_ref() else {
+                return;
+            };
+            if !comparison.accepts(&vote_value) {
+                console_error!(format!(
+                    "BUG: vote {vote_value:?} is not an answer to comparison \
+                     {}",
+                    comparison.id
+                ));
+                return;
+            }
+            let answer = Answer {
+                vote_value,
+                timing: display_times.borrow().answered_now(),
+                interruptions: attention.borrow().interruptions_now(),
+                session: Session::collect(comparison.images.len()),
+            };
+            match E::CONFIDENCE_SCALE {
+                Some(_) => pending_vote.set(Some(answer)),
+                None => submit_vote.emit((answer, None)),
+            }
+        })
+    };
+
+    let on_confidence = {
+        let pending_vote = pending_vote.clone();
+        Callback::from(move |confidence: u8| {
+            if let Some(answer) = (*pending_vote).clone() {
+                pending_vote.set(None);
+                submit_vote.emit((answer, Some(confidence)));
+            }
+        })
+    };
+
+    let close_confidence_modal = {
+        let pending_vote = pending_vote.clone();
+        Callback::from(move |_| pending_vote.set(None))
+    };
+
+    let fetch_comparison = {
+        let api = api.clone();
+        let loading = loading.clone();
+        let request_error = request_error.clone();
+        let show_instructions_modal = show_instructions_modal.clone();
+        let comparison_state = comparison_state.clone();
+        let user_state = user_state.clone();
+        let prefetch = prefetch.clone();
+        let dirname = props.dirname.to_string();
+
+        || {
+            if *loading && request_error.is_none() {
+                wasm_bindgen_futures::spawn_local(async move {
+                    let user_response = get_user(&api).await;
+                    let response = match user_response {
+                        Ok(user) => next_comparison(
+                            &api,
+                            &prefetch,
+                            user.id.clone(),
+                            dirname.clone(),
+                        )
+                        .await
+                        .map(|comparison| (user, comparison)),
+                        Err(error) => Err(error),
+                    };
+                    match response {
+                        Ok((user, comparison)) => {
+                            loading.set(false);
+                            if user.votes == 0 {
+                                show_instructions_modal.set(true);
+                            }
+                            prefetch.borrow_mut().serve(&comparison);
+                            prefetch_comparisons(
+                                api,
+                                prefetch,
+                                user.id.clone(),
+                                dirname,
+                            );
+                            user_state.set(user);
+                            comparison_state.set(Some(comparison));
+                        },
+                        Err(error) => {
+                            console_error!(error.to_string());
+                            request_error.set(Some(error));
+                        },
+                    }
+                });
+            }
+        }
+    };
+
+    {
+        let comparison_state = comparison_state.clone();
+        let fetch_attempt = fetch_attempt.clone();
+
+        use_effect_with((comparison_state, fetch_attempt), move |_| {
+            fetch_comparison()
+        });
+    }
+
+    let comparison_to_display = (*comparison_state).clone().unwrap_or_default();
+
+    html! {
+        <section
+            id="compare"
+            class={classes!["h-full", "flex", "flex-col"]}
+        >
+            <Header
+                user={(*user_state).clone()}
+                onreload={reload}
+                pending_votes={*pending_votes_state}
+            />
+            <Prompt name={E::PROMPT} />
+            // the bar fills up with the timer, from the paint
+            if let (Some(duration), true) =
+                (E::MIN_VIEWING_TIME, dwelling && painted)
+            {
+                <DwellProgress
+                    key={shown_id.unwrap_or_default()}
+                    duration={duration}
+                />
+            }
+            if E::PRESENTATIONS.len() > 1 {
+                <PresentationSelect
+                    presentations={E::PRESENTATIONS}
+                    selected={*presentation}
+                    onselect={on_presentation_select}
+                />
+            }
+            <E::Stimuli
+                loading={*loading}
+                images={comparison_to_display.images}
+                reference={comparison_to_display.reference}
+                exposure={exposure.phase}
+                dwelling={dwelling}
+                rating_scale={E::RATING_SCALE}
+                onvote={on_vote}
+                presentation={*presentation}
+                flicker_interval={E::FLICKER_INTERVAL}
+                loupe_magnification={E::LOUPE_MAGNIFICATION}
+                onviewport={on_viewport}
+            />
+            <Footer>
+                <Button
+                    id={"open_instructions_modal_button"}
+                    onclick={open_instructions_modal}
+                >
+                    <QuestionMarkCircle
+                        class={classes![
+                            "h-8",
+                            "stroke-gray-100",
+                        ]}
+                    />
+                    <span class={classes!["sr-only"]}>
+                        { instructions_button_sr }
+                    </span>
+                </Button>
+            </Footer>
+            if let (Some(scale), Some(_)) =
+                (E::CONFIDENCE_SCALE, pending_vote.as_ref())
+            {
+                <ConfidenceModal
+                    scale={scale}
+                    onselect={on_confidence}
+                    onclose={close_confidence_modal}
+                />
+            }
+            if *show_catch_trial_warning && request_error.is_none() {
+                <CatchTrialWarningModal onclose={close_catch_trial_warning} />
+            }
+            if *show_instructions_modal && request_error.is_none() {
+                <InstructionsModal onclose={close_instructions_modal} />
+            }
+            if let Some(error) = (*request_error).clone() {
+                <FatalErrorModal
+                    onclose={close_fatal_error_modal}
+                    onretry={error.is_retryable().then_some(retry)}
+                    error={error}
+                />
+            }
+        </section>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        ops::RangeInclusive,
+        sync::atomic::Ordering,
+        time::Duration,
+    };
+
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+    use yew::{
+        function_component,
+        html,
+        Html,
+    };
+
+    use super::{
+        Experiment,
+        ExperimentPage,
+        Exposure,
+        ExposureMask,
+        StimuliProps,
+    };
+    use crate::{
+        click_button,
+        dom::DOM,
+        markdown_to_decoded_html,
+        render_yew_component,
+        request::{
+            images::CatchTrial,
+            vote_queue::enqueue_vote,
+            Comparison,
+            FakeClient,
+            RequestError,
+            Vote,
+            VoteValue,
+        },
+        wasm_sleep_in_ms,
+        Language,
+        AVAILABLE_LANGUAGES,
+        DEFAULT_LANGUAGE,
+    };
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    struct TestExperiment;
+
+    #[function_component(TestStimuli)]
+    fn test_stimuli(props: &StimuliProps) -> Html {
+        let onclick = {
+            let onvote = props.onvote.clone();
+            move |_| onvote.emit(VoteValue::Equal)
+        };
+
+        html! {
+            <section id="test_stimuli">
+                if !props.loading {
+                    <span id="test_images">{ props.images.join(",") }</span>
+                    <button id="test_vote_button" onclick={onclick} />
+                }
+                <ExposureMask phase={props.exposure} />
+            </section>
+        }
+    }
+
+    fn comparison(id: &str) -> Comparison {
+        Comparison {
+            id: id.to_string(),
+            images: vec![format!("/{id}/0.png"), format!("/{id}/1.png")],
+            reference: None,
+            catch_trial: None,
+        }
+    }
+
+    fn shown_images() -> Option<String> {
+        DOM::get_element_by_id("test_images").map(|images| images.inner_html())
+    }
+
+    fn click_vote_button() {
+        DOM::get_button_by_id("test_vote_button")
+            .expect("Element #test_vote_button to be present")
+            .dyn_into::<web_sys::HtmlElement>()
+            .expect("Element to be castable to HtmlElement")
+            .click();
+    }
+
+    impl Experiment for TestExperiment {
+        type Stimuli = TestStimuli;
+
+        const PROMPT: &'static str = "which_is_best_prompt";
+    }
+
+    type TestExperimentPage = ExperimentPage<TestExperiment>;
+
+    struct TestExposedExperiment;
+
+    impl Experiment for TestExposedExperiment {
+        type Stimuli = TestStimuli;
+
+        const EXPOSURE: Option<Exposure> = Some(Exposure {
+            duration: Duration::from_millis(200),
+            pattern: true,
+        });
+        const PROMPT: &'static str = "which_is_best_prompt";
+    }
+
+    type TestExposedExperimentPage = ExperimentPage<TestExposedExperiment>;
+
+    struct TestConfidenceExperiment;
+
+    impl Experiment for TestConfidenceExperiment {
+        type Stimuli = TestStimuli;
+
+        const CONFIDENCE_SCALE: Option<RangeInclusive<u8>> = Some(1..=3);
+        const PROMPT: &'static str = "which_is_best_prompt";
+    }
+
+    type TestConfidenceExperimentPage =
+        ExperimentPage<TestConfidenceExperiment>;
+
+    struct TestDwellingExperiment;
+
+    impl Experiment for TestDwellingExperiment {
+        type Stimuli = TestStimuli;
+
+        const MIN_VIEWING_TIME: Option<Duration> =
+            Some(Duration::from_millis(200));
+        const PROMPT: &'static str = "which_is_best_prompt";
+    }
+
+    type TestDwellingExperimentPage = ExperimentPage<TestDwellingExperiment>;
+
+    #[wasm_bindgen_test]
+    async fn experiment_stimuli_are_rendered() {
+        render_yew_component!(TestExperimentPage);
+        wasm_sleep_in_ms(150).await;
+
+        assert!(DOM::get_element_by_id("test_stimuli").is_some());
+    }
+
+    #[wasm_bindgen_test]
+    async fn experiment_prompt_is_rendered() {
+        render_yew_component!(TestExperimentPage);
+        wasm_sleep_in_ms(150).await;
+
+        assert!(DOM::get_element_by_id(TestExperiment::PROMPT).is_some());
+    }
+
+    #[wasm_bindgen_test]
+    async fn prefetched_comparison_is_shown_right_after_voting() {
+        let api = FakeClient::default()
+            .comparisons(vec![comparison("a"), comparison("b")])
+            .comparison_latency(Duration::from_millis(100));
+
+        render_yew_component!(TestExperimentPage, api);
+        wasm_sleep_in_ms(300).await; // allow comparison "b" to be prefetched
+        assert_eq!(shown_images().as_deref(), Some("/a/0.png,/a/1.png"));
+
+        click_vote_button();
+        wasm_sleep_in_ms(50).await; // less than it takes to fetch one
+        assert_eq!(shown_images().as_deref(), Some("/b/0.png,/b/1.png"));
+    }
+
+    #[wasm_bindgen_test]
+    async fn invalidated_prefetched_comparison_is_not_shown() {
+        let api = FakeClient::default()
+            .comparisons(vec![
+                comparison("a"),
+                comparison("b"),
+                comparison("c"),
+            ])
+            .comparison_latency(Duration::from_millis(100))
+            .invalidate("b");
+
+        render_yew_component!(TestExperimentPage, api);
+        wasm_sleep_in_ms(300).await; // allow comparison "b" to be prefetched
+
+        click_vote_button();
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+        assert!(shown_images().is_none());
+        wasm_sleep_in_ms(150).await; // allow comparison "c" to be fetched
+        assert_eq!(shown_images().as_deref(), Some("/c/0.png,/c/1.png"));
+    }
+
+    #[wasm_bindgen_test]
+    async fn unconfirmed_prefetched_comparison_is_not_shown() {
+        let api = FakeClient::default()
+            .comparisons(vec![
+                comparison("a"),
+                comparison("b"),
+                comparison("c"),
+            ])
+            .comparison_latency(Duration::from_millis(100))
+            .validity_error(RequestError::Network("test".to_string()));
+
+        render_yew_component!(TestExperimentPage, api);
+        wasm_sleep_in_ms(300).await; // allow comparison "b" to be prefetched
+
+        click_vote_button();
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+        assert!(shown_images().is_none());
+        wasm_sleep_in_ms(150).await; // allow comparison "c" to be fetched
+        assert_eq!(shown_images().as_deref(), Some("/c/0.png,/c/1.png"));
+    }
+
+    #[wasm_bindgen_test]
+    fn change_user_button_markdown_exists() {
+        // add 1 to len to run even if no languages are available
+        for language_index in 0..AVAILABLE_LANGUAGES.len() + 1 {
+            let language = Language {
+                index: language_index,
+            };
+            let file = language.load_file("change_user_button.md");
+
+            assert!(file.is_some())
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn button_to_change_user_exists() {
+        // add 1 to len to run even if no languages are available
+        for language_index in 0..AVAILABLE_LANGUAGES.len() + 1 {
+            DEFAULT_LANGUAGE.store(language_index, Ordering::SeqCst);
+
+            render_yew_component!(TestExperimentPage);
+            wasm_sleep_in_ms(150).await;
+
+            let language = Language::default();
+            let expected = language.load_file("change_user_button.md");
+            let expected = markdown_to_decoded_html(expected.unwrap_or(""));
+
+            assert!(DOM::has_button_with_inner_html(&expected));
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn change_user_modal_is_closed_by_default() {
+        render_yew_component!(TestExperimentPage);
+        wasm_sleep_in_ms(150).await;
+
+        assert!(DOM::get_element_by_id("change_user_modal").is_none());
+    }
+
+    #[wasm_bindgen_test]
+    async fn button_to_change_user_shows_change_user_modal() {
+        render_yew_component!(TestExperimentPage);
+        wasm_sleep_in_ms(150).await;
+
+        let button = DOM::get_button_by_id("change_user_button")
+            .expect("Element #change_user_button to be present")
+            .dyn_into::<web_sys::HtmlElement>()
+            .expect("Element to be castable to HtmlElement");
+
+        button.click();
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+        assert!(DOM::get_element_by_id("change_user_modal").is_some());
+    }
+
+    #[wasm_bindgen_test]
+    async fn change_user_modal_can_be_closed() {
+        render_yew_component!(TestExperimentPage);
+        wasm_sleep_in_ms(150).await;
+
+        let open_button = DOM::get_button_by_id("change_user_button")
+            .expect("Element #change_user_button to be present")
+            .dyn_into::<web_sys::HtmlElement>()
+            .expect("Element to be castable to HtmlElement");
+
+        open_button.click();
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+
+        let close_button =
+            DOM::get_button_by_id("close_change_user_modal_button")
+                .expect("Element #close_change_user_modal_button to be present")
+                .dyn_into::<web_sys::HtmlElement>()
+                .expect("Element to be castable to HtmlElement");
+
+        close_button.click();
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+        assert!(DOM::get_element_by_id("change_user_modal").is_none());
+    }
+
+    #[wasm_bindgen_test]
+    async fn change_user_modal_can_be_closed_by_cancel_button() {
+        render_yew_component!(TestExperimentPage);
+        wasm_sleep_in_ms(150).await;
+
+        let open_button = DOM::get_button_by_id("change_user_button")
+            .expect("Element #change_user_button to be present")
+            .dyn_into::<web_sys::HtmlElement>()
+            .expect("Element to be castable to HtmlElement");
+
+        open_button.click();
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+
+        let close_button = DOM::get_button_by_id("change_user_cancel_button")
+            .expect("Element #change_user_cancel_button to be present")
+            .dyn_into::<web_sys::HtmlElement>()
+            .expect("Element to be castable to HtmlElement");
+
+        close_button.click();
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+        assert!(DOM::get_element_by_id("change_user_modal").is_none());
+    }
+
+    #[wasm_bindgen_test]
+    async fn confirm_reset_user_in_change_user_modal_reloads_page() {
+        let votes: usize = rand::random();
+        DOM::local_storage()
+            .unwrap()
+            .set_item("user_id", "123456")
+            .unwrap();
+
+        render_yew_component!(
+            TestExperimentPage,
+            FakeClient::default().user_votes(votes)
+        );
+        wasm_sleep_in_ms(150).await;
+
+        let open_button = DOM::get_button_by_id("change_user_button")
+            .expect("Element #change_user_button to be present")
+            .dyn_into::<web_sys::HtmlElement>()
+            .expect("Element to be castable to HtmlElement");
+
+        open_button.click();
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+
+        let confirm_button =
+            DOM::get_button_by_id("change_user_confirm_button")
+                .expect("Element #change_user_confirm_button to be present")
+                .dyn_into::<web_sys::HtmlElement>()
+                .expect("Element to be castable to HtmlElement");
+
+        confirm_button.click();
+        wasm_sleep_in_ms(150).await; // allow page to re-render
+        assert!(DOM::get_element_by_id("instructions_modal").is_some());
+    }
+
+    #[wasm_bindgen_test]
+    async fn button_to_finish_comparing_exists() {
+        render_yew_component!(TestExperimentPage);
+        wasm_sleep_in_ms(150).await;
+
+        assert!(DOM::get_button_by_id("finish_comparing_button").is_some());
+    }
+
+    #[wasm_bindgen_test]
+    fn finish_comparing_button_markdown_exists() {
+        // add 1 to len to run even if no languages are available
+        for language_index in 0..AVAILABLE_LANGUAGES.len() + 1 {
+            let language = Language {
+                index: language_index,
+            };
+            let file = language.load_file("finish_comparing_button.md");
+
+            assert!(file.is_some())
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn button_to_finish_comparing_shows_user_votes() {
+        let votes: usize = rand::random();
+        DOM::local_storage()
+            .unwrap()
+            .set_item("user_id", "123456")
+            .unwrap();
+
+        // add 1 to len to run even if no languages are available
+        for language_index in 0..AVAILABLE_LANGUAGES.len() + 1 {
+            DEFAULT_LANGUAGE.store(language_index, Ordering::SeqCst);
+
+            render_yew_component!(
+                TestExperimentPage,
+                FakeClient::default().user_votes(votes)
+            );
+            wasm_sleep_in_ms(150).await;
+
+            let language = Language::default();
+            let expected = language.load_file("finish_comparing_button.md");
+            let expected = expected
+                .unwrap_or("")
+                .replace("{votes}", &votes.to_string());
+            let expected = markdown_to_decoded_html(&expected);
+
+            let button = DOM::get_button_by_id("finish_comparing_button")
+                .expect("finish_comparing_button to be present");
+
+            assert_eq!(button.inner_html(), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn finish_comparing_modal_is_closed_by_default() {
+        render_yew_component!(TestExperimentPage);
+        wasm_sleep_in_ms(150).await;
+
+        assert!(DOM::get_element_by_id("finish_comparing_modal").is_none());
+    }
+
+    #[wasm_bindgen_test]
+    async fn button_to_finish_comparing_shows_change_user_modal() {
+        render_yew_component!(TestExperimentPage);
+        wasm_sleep_in_ms(150).await;
+
+        let button = DOM::get_button_by_id("finish_comparing_button")
+            .expect("Element #finish_comparing_button to be present")
+            .dyn_into::<web_sys::HtmlElement>()
+            .expect("Element to be castable to HtmlElement");
+
+        button.click();
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+        assert!(DOM::get_element_by_id("finish_comparing_modal").is_some());
+    }
+
+    #[wasm_bindgen_test]
+    async fn finish_comparing_modal_can_be_closed() {
+        render_yew_component!(TestExperimentPage);
+        wasm_sleep_in_ms(150).await;
+
+        let open_button = DOM::get_button_by_id("finish_comparing_button")
+            .expect("Element #finish_comparing_button to be present")
+            .dyn_into::<web_sys::HtmlElement>()
+            .expect("Element to be castable to HtmlElement");
+
+        open_button.click();
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+
+        let close_button =
+            DOM::get_button_by_id("close_finish_comparing_modal_button")
+                .expect(
+                    "Element #close_finish_comparing_modal_button to be \
+                     present",
+                )
+                .dyn_into::<web_sys::HtmlElement>()
+                .expect("Element to be castable to HtmlElement");
+
+        close_button.click();
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+        assert!(DOM::get_element_by_id("finish_comparing_modal").is_none());
+    }
+
+    #[wasm_bindgen_test]
+    fn pending_votes_indicator_markdown_exists() {
+        // add 1 to len to run even if no languages are available
+        for language_index in 0..AVAILABLE_LANGUAGES.len() + 1 {
+            let language = Language {
+                index: language_index,
+            };
+            let file = language.load_file("pending_votes_indicator.md");
+
+            assert!(file.is_some())
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn pending_votes_indicator_is_hidden_when_queue_is_empty() {
+        DOM::local_storage().unwrap().delete("vote_queue").unwrap();
+
+        render_yew_component!(TestExperimentPage);
+        wasm_sleep_in_ms(150).await;
+
+        assert!(DOM::get_element_by_id("pending_votes_indicator").is_none());
+    }
+
+    #[wasm_bindgen_test]
+    async fn pending_votes_indicator_shows_queued_votes() {
+        DOM::local_storage().unwrap().delete("vote_queue").unwrap();
+        enqueue_vote(
+            &Vote::build(Comparison::default())
+                .user("44444444444444444444444444444444".to_string())
+                .vote(VoteValue::Equal),
+        )
+        .expect("vote to be enqueued");
+
+        render_yew_component!(TestExperimentPage);
+        wasm_sleep_in_ms(150).await;
+
+        assert!(DOM::get_element_by_id("pending_votes_indicator").is_some());
+        DOM::local_storage().unwrap().delete("vote_queue").unwrap();
+    }
+
+    #[wasm_bindgen_test]
+    async fn show_fatal_error_modal_when_get_user_returns_error() {
+        render_yew_component!(
+            TestExperimentPage,
+            FakeClient::default()
+                .user_error(RequestError::Network("test".to_string()))
+        );
+        wasm_sleep_in_ms(150).await;
+
+        assert!(DOM::get_element_by_id("fatal_error_modal").is_some());
+    }
+
+    #[wasm_bindgen_test()]
+    async fn show_fatal_error_modal_when_get_images_returns_error() {
+        render_yew_component!(
+            TestExperimentPage,
+            FakeClient::default()
+                .comparison_error(RequestError::Network("test".to_string()))
+        );
+        wasm_sleep_in_ms(150).await;
+
+        assert!(DOM::get_element_by_id("fatal_error_modal").is_some());
+    }
+
+    #[wasm_bindgen_test]
+    async fn when_user_has_0_votes_show_instructions_modal() {
+        DOM::local_storage()
+            .unwrap()
+            .set_item("user_id", "123456")
+            .unwrap();
+
+        render_yew_component!(
+            TestExperimentPage,
+            FakeClient::default().user_votes(0)
+        );
+        wasm_sleep_in_ms(150).await;
+
+        assert!(DOM::get_element_by_id("instructions_modal").is_some());
+    }
+
+    #[wasm_bindgen_test]
+    async fn when_user_has_more_than_0_votes_do_not_show_instructions_modal() {
+        DOM::local_storage()
+            .unwrap()
+            .set_item("user_id", "123456")
+            .unwrap();
+
+        render_yew_component!(
+            TestExperimentPage,
+            FakeClient::default().user_votes(1)
+        );
+        wasm_sleep_in_ms(150).await;
+
+        assert!(DOM::get_element_by_id("instructions_modal").is_none());
+    }
+
+    #[wasm_bindgen_test]
+    async fn button_to_show_instructions_modal_exists() {
+        render_yew_component!(TestExperimentPage);
+        wasm_sleep_in_ms(150).await;
+
+        assert!(
+            DOM::get_button_by_id("open_instructions_modal_button").is_some()
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn instructions_button_sr_markdown_exists() {
+        // add 1 to len to run even if no languages are available
+        for language_index in 0..AVAILABLE_LANGUAGES.len() + 1 {
+            let language = Language {
+                index: language_index,
+            };
+            let file = language.load_file("instructions_button_sr.md");
+
+            assert!(file.is_some())
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn instructions_button_sr_text_is_rendered() {
+        // add 1 to len to run even if no languages are available
+        for language_index in 0..AVAILABLE_LANGUAGES.len() + 1 {
+            DEFAULT_LANGUAGE.store(language_index, Ordering::SeqCst);
+
+            render_yew_component!(TestExperimentPage);
+            wasm_sleep_in_ms(50).await;
+
+            let language = Language::default();
+            let expected = language.load_file("instructions_button_sr.md");
+            let expected = markdown_to_decoded_html(expected.unwrap_or(""));
+
+            let text = DOM::get_element_by_id("open_instructions_modal_button")
+                .expect("Element #open_instructions_modal_button to exist")
+                .last_element_child()
+                .expect("Last element child to exist");
+
+            assert_eq!(text.inner_html(), expected);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn button_to_show_instructions_modal_works() {
+        DOM::local_storage()
+            .unwrap()
+            .set_item("user_id", "123456")
+            .unwrap();
+
+        render_yew_component!(
+            TestExperimentPage,
+            FakeClient::default().user_votes(1)
+        );
+        wasm_sleep_in_ms(150).await;
+
+        let button = DOM::get_button_by_id("open_instructions_modal_button")
+            .expect("Element #open_instructions_modal_button to be present")
+            .dyn_into::<web_sys::HtmlElement>()
+            .expect("Element to be castable to HtmlElement");
+
+        button.click();
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+        assert!(DOM::get_element_by_id("instructions_modal").is_some());
+    }
+
+    #[wasm_bindgen_test]
+    async fn instructions_modal_can_be_closed() {
+        DOM::local_storage()
+            .unwrap()
+            .set_item("user_id", "123456")
+            .unwrap();
+
+        render_yew_component!(
+            TestExperimentPage,
+            FakeClient::default().user_votes(0)
+        );
+        wasm_sleep_in_ms(150).await;
+
+        let button = DOM::get_button_by_id("close_instructions_modal_button")
+            .expect("Element #close_instructions_modal_button to be present")
+            .dyn_into::<web_sys::HtmlElement>()
+            .expect("Element to be castable to HtmlElement");
+
+        button.click();
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+        assert!(DOM::get_element_by_id("instructions_modal").is_none());
+    }
+
+    #[wasm_bindgen_test]
+    async fn comparison_is_masked_after_its_exposure() {
+        render_yew_component!(TestExposedExperimentPage);
+        wasm_sleep_in_ms(100).await; // less than the exposure
+        assert!(shown_images().is_some());
+        assert!(DOM::get_element_by_id("exposure_mask").is_none());
+
+        wasm_sleep_in_ms(300).await; // allow exposure to end
+        assert!(DOM::get_element_by_id("exposure_mask").is_some());
+    }
+
+    #[wasm_bindgen_test]
+    async fn votes_are_ignored_during_exposure() {
+        let api = FakeClient::default();
+
+        render_yew_component!(TestExposedExperimentPage, api.clone());
+        wasm_sleep_in_ms(100).await; // less than the exposure
+
+        click_vote_button();
+        wasm_sleep_in_ms(50).await; // allow vote to be posted
+        assert!(api.posted_votes().is_empty());
+    }
+
+    #[wasm_bindgen_test]
+    async fn vote_records_measured_exposure() {
+        let api = FakeClient::default();
+
+        render_yew_component!(TestExposedExperimentPage, api.clone());
+        wasm_sleep_in_ms(400).await; // allow exposure to end
+
+        click_vote_button();
+        wasm_sleep_in_ms(50).await; // allow vote to be posted
+
+        let votes = api.posted_votes();
+        assert_eq!(votes.len(), 1);
+        let exposure = votes[0].recorded_exposure().expect("to be measured");
+        assert!(exposure >= 200.0);
+    }
+
+    #[wasm_bindgen_test]
+    async fn vote_without_exposure_records_none() {
+        let api = FakeClient::default();
+
+        render_yew_component!(TestExperimentPage, api.clone());
+        wasm_sleep_in_ms(150).await;
+
+        click_vote_button();
+        wasm_sleep_in_ms(50).await; // allow vote to be posted
+
+        let votes = api.posted_votes();
+        assert_eq!(votes.len(), 1);
+        assert_eq!(votes[0].recorded_exposure(), None);
+    }
+
+    #[wasm_bindgen_test]
+    async fn confidence_is_asked_before_voting() {
+        let api = FakeClient::default();
+
+        render_yew_component!(TestConfidenceExperimentPage, api.clone());
+        wasm_sleep_in_ms(150).await;
+
+        click_vote_button();
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+        assert!(DOM::get_element_by_id("confidence_modal").is_some());
+        assert!(api.posted_votes().is_empty());
+
+        click_button("confidence_button_2");
+        wasm_sleep_in_ms(50).await; // allow vote to be posted
+        assert!(DOM::get_element_by_id("confidence_modal").is_none());
+
+        let votes = api.posted_votes();
+        assert_eq!(votes.len(), 1);
+        assert_eq!(votes[0].vote_value(), &VoteValue::Equal);
+        assert_eq!(votes[0].recorded_confidence(), Some(2));
+    }
+
+    #[wasm_bindgen_test]
+    async fn cancelling_confidence_goes_back_to_choosing() {
+        let api = FakeClient::default();
+
+        render_yew_component!(TestConfidenceExperimentPage, api.clone());
+        wasm_sleep_in_ms(150).await;
+
+        click_vote_button();
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+        click_button("confidence_cancel_button");
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+
+        assert!(DOM::get_element_by_id("confidence_modal").is_none());
+        assert!(shown_images().is_some());
+        assert!(api.posted_votes().is_empty());
+    }
+
+    #[wasm_bindgen_test]
+    async fn vote_without_confidence_scale_is_submitted_right_away() {
+        let api = FakeClient::default();
+
+        render_yew_component!(TestExperimentPage, api.clone());
+        wasm_sleep_in_ms(150).await;
+
+        click_vote_button();
+        wasm_sleep_in_ms(50).await; // allow vote to be posted
+
+        assert!(DOM::get_element_by_id("confidence_modal").is_none());
+        let votes = api.posted_votes();
+        assert_eq!(votes.len(), 1);
+        assert_eq!(votes[0].recorded_confidence(), None);
+    }
+
+    fn catch_trial(id: &str, catch_trial: CatchTrial) -> Comparison {
+        Comparison {
+            catch_trial: Some(catch_trial),
+            ..comparison(id)
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn vote_records_catch_trial_result() {
+        let api = FakeClient::default().comparisons(vec![
+            catch_trial("a", CatchTrial::Identical),
+            comparison("b"),
+        ]);
+
+        render_yew_component!(TestExperimentPage, api.clone());
+        wasm_sleep_in_ms(150).await;
+        click_vote_button();
+        wasm_sleep_in_ms(150).await; // allow vote to be posted
+        click_vote_button();
+        wasm_sleep_in_ms(50).await; // allow vote to be posted
+
+        let votes = api.posted_votes();
+        assert_eq!(votes.len(), 2);
+        assert_eq!(votes[0].recorded_catch_trial(), Some(true));
+        assert_eq!(votes[1].recorded_catch_trial(), None);
+    }
+
+    #[wasm_bindgen_test]
+    async fn failed_catch_trials_show_warning_modal() {
+        let warn_after = TestExperiment::CATCH_TRIAL_FAILURES_BEFORE_WARNING;
+        // voting "equal" fails trials with an obviously better image
+        let failed = |index: usize| {
+            let id = format!("failed_{index}");
+            catch_trial(&id, CatchTrial::Better(format!("/{id}/0.png")))
+        };
+        let mut comparisons = (0..warn_after).map(failed).collect::<Vec<_>>();
+        comparisons.push(comparison("c"));
+        let api = FakeClient::default().comparisons(comparisons);
+
+        render_yew_component!(TestExperimentPage, api.clone());
+        wasm_sleep_in_ms(150).await;
+        for _ in 1..warn_after {
+            click_vote_button();
+            wasm_sleep_in_ms(150).await; // allow vote to be posted
+            assert!(
+                DOM::get_element_by_id("catch_trial_warning_modal").is_none()
+            );
+        }
+
+        click_vote_button();
+        wasm_sleep_in_ms(150).await; // allow vote to be posted
+        assert!(DOM::get_element_by_id("catch_trial_warning_modal").is_some());
+
+        click_button("catch_trial_warning_button");
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+        assert!(DOM::get_element_by_id("catch_trial_warning_modal").is_none());
+        assert_eq!(
+            api.posted_votes()[warn_after - 1].recorded_catch_trial(),
+            Some(false)
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn vote_records_when_it_was_answered() {
+        let api = FakeClient::default();
+
+        render_yew_component!(TestExperimentPage, api.clone());
+        wasm_sleep_in_ms(150).await;
+
+        let before = DOM::now().expect("performance.now() to be available");
+        click_vote_button();
+        wasm_sleep_in_ms(50).await; // allow vote to be posted
+
+        let votes = api.posted_votes();
+        assert_eq!(votes.len(), 1);
+        let timing = votes[0].recorded_timing().expect("Timing to be recorded");
+        assert!(timing.answered_ms >= before);
+        if let Some(painted) = timing.painted_ms {
+            assert!(painted <= timing.answered_ms);
+            assert_eq!(
+                timing.response_time_ms,
+                Some(timing.answered_ms - painted)
+            );
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn progress_is_shown_within_minimum_viewing_time() {
+        render_yew_component!(TestDwellingExperimentPage);
+        wasm_sleep_in_ms(100).await;
+        assert!(DOM::get_element_by_id("dwell_progress").is_some());
+
+        wasm_sleep_in_ms(250).await; // allow minimum viewing time to pass
+        assert!(DOM::get_element_by_id("dwell_progress").is_none());
+    }
+
+    #[wasm_bindgen_test]
+    async fn votes_are_ignored_within_minimum_viewing_time() {
+        let api = FakeClient::default();
+
+        render_yew_component!(TestDwellingExperimentPage, api.clone());
+        wasm_sleep_in_ms(100).await;
+        click_vote_button();
+        wasm_sleep_in_ms(50).await; // allow vote to be posted
+        assert!(api.posted_votes().is_empty());
+
+        wasm_sleep_in_ms(200).await; // allow minimum viewing time to pass
+        click_vote_button();
+        wasm_sleep_in_ms(50).await; // allow vote to be posted
+        assert_eq!(api.posted_votes().len(), 1);
+    }
+
+    #[wasm_bindgen_test]
+    async fn vote_records_leaving_the_window() {
+        let api = FakeClient::default();
+
+        render_yew_component!(TestExperimentPage, api.clone());
+        wasm_sleep_in_ms(150).await;
+
+        let window = DOM::window().expect("Window to exist");
+        for event in ["blur", "focus", "blur", "blur", "focus"] {
+            window
+                .dispatch_event(
+                    &web_sys::Event::new(event)
+                        .expect("Event to be constructible"),
+                )
+                .expect("Event to be dispatched");
+        }
+        click_vote_button();
+        wasm_sleep_in_ms(50).await; // allow vote to be posted
+
+        let votes = api.posted_votes();
+        assert_eq!(votes.len(), 1);
+        let interruptions = votes[0]
+            .recorded_interruptions()
+            .expect("Interruptions to be recorded");
+        assert_eq!(interruptions.count, 2);
+        assert_eq!(interruptions.hidden_ms, 0.0);
+    }
+}
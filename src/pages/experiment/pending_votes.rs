@@ -0,0 +1,118 @@
+use std::{
+    cell::Cell,
+    rc::Rc,
+    time::Duration,
+};
+
+use gloo_events::EventListener;
+use yew::{
+    hook,
+    platform::time::sleep,
+    use_effect_with,
+    use_state_eq,
+    UseStateHandle,
+};
+
+use crate::{
+    dom::DOM,
+    request::{
+        use_api,
+        vote_queue::{
+            pending_votes,
+            replay_votes,
+        },
+    },
+};
+
+/// Longest wait between two replays of the vote queue.
+const MAX_REPLAY_DELAY: Duration = Duration::from_secs(60);
+
+fn replay_delay(attempt: u32) -> Duration {
+    Duration::from_secs(2_u64.saturating_pow(attempt)).min(MAX_REPLAY_DELAY)
+}
+
+/// Number of votes waiting in the offline queue.
+///
+/// While any are pending, the queue is replayed with exponential backoff,
+/// and immediately whenever the browser reports being back `online`. Set
+/// the handle after queueing a vote so the replay is scheduled.
+#[hook]
+pub(super) fn use_pending_votes() -> UseStateHandle<usize> {
+    let api = use_api();
+    let pending = use_state_eq(|| pending_votes().len());
+    let attempt = use_state_eq(|| 0_u32);
+
+    {
+        let api = api.clone();
+        let pending = pending.clone();
+        let attempt = attempt.clone();
+        use_effect_with((*pending, *attempt), move |(count, current)| {
+            let cancelled = Rc::new(Cell::new(false));
+            if *count > 0 {
+                let cancelled = cancelled.clone();
+                let delay = replay_delay(*current);
+                let next = current.saturating_add(1);
+                wasm_bindgen_futures::spawn_local(async move {
+                    sleep(delay).await;
+                    if cancelled.get() {
+                        return;
+                    }
+                    let remaining = replay_votes(&api).await;
+                    pending.set(remaining);
+                    attempt.set(if remaining > 0 { next } else { 0 });
+                });
+            }
+            move || cancelled.set(true)
+        });
+    }
+
+    {
+        let pending = pending.clone();
+        let attempt = attempt.clone();
+        use_effect_with((), move |_| {
+            let listener = DOM::window().map(|window| {
+                EventListener::new(&window, "online", move |_| {
+                    let api = api.clone();
+                    let pending = pending.clone();
+                    let attempt = attempt.clone();
+                    wasm_bindgen_futures::spawn_local(async move {
+                        pending.set(replay_votes(&api).await);
+                        attempt.set(0);
+                    });
+                })
+            });
+            move || drop(listener)
+        });
+    }
+
+    pending
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+
+    use super::{
+        replay_delay,
+        MAX_REPLAY_DELAY,
+    };
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    fn replay_delay_doubles_with_each_attempt() {
+        assert_eq!(replay_delay(0), Duration::from_secs(1));
+        assert_eq!(replay_delay(1), Duration::from_secs(2));
+        assert_eq!(replay_delay(3), Duration::from_secs(8));
+    }
+
+    #[wasm_bindgen_test]
+    fn replay_delay_is_capped() {
+        assert_eq!(replay_delay(20), MAX_REPLAY_DELAY);
+        assert_eq!(replay_delay(u32::MAX), MAX_REPLAY_DELAY);
+    }
+}
@@ -0,0 +1,265 @@
+use std::{
+    cell::RefCell,
+    collections::{
+        HashSet,
+        VecDeque,
+    },
+    rc::Rc,
+};
+
+use crate::{
+    dom::console_error,
+    request::{
+        Api,
+        Comparison,
+        RequestError,
+    },
+};
+
+/// Comparisons fetched (images included) ahead of the one being shown, so
+/// the next trial does not wait on the network.
+pub(super) struct PrefetchBuffer {
+    depth: usize,
+    /// Bumped whenever the buffered comparisons stop applying (other user
+    /// or stimulus set), so prefetches still in flight are discarded.
+    generation: usize,
+    comparisons: VecDeque<Comparison>,
+    current: Option<String>,
+    served: HashSet<String>,
+    in_flight: bool,
+}
+
+impl PrefetchBuffer {
+    pub(super) fn new(depth: usize) -> Self {
+        Self {
+            depth,
+            generation: 0,
+            comparisons: VecDeque::new(),
+            current: None,
+            served: HashSet::new(),
+            in_flight: false,
+        }
+    }
+
+    pub(super) fn clear(&mut self) {
+        self.generation += 1;
+        self.comparisons.clear();
+        self.current = None;
+        self.served.clear();
+        self.in_flight = false;
+    }
+
+    fn pop(&mut self) -> Option<Comparison> {
+        self.comparisons.pop_front()
+    }
+
+    /// Records the comparison being shown, so it is never buffered (or
+    /// served from the buffer) again, e.g. when fetched both directly and
+    /// by a prefetch.
+    pub(super) fn serve(&mut self, comparison: &Comparison) {
+        self.current = Some(comparison.id.clone());
+        self.served.insert(comparison.id.clone());
+        self.comparisons
+            .retain(|buffered| buffered.id != comparison.id);
+    }
+
+    /// Ids of the comparisons shown or buffered, which the API should not
+    /// hand out again.
+    fn exclude(&self) -> Vec<String> {
+        self.current
+            .iter()
+            .chain(self.comparisons.iter().map(|comparison| &comparison.id))
+            .cloned()
+            .collect()
+    }
+
+    /// Claims the next prefetch, unless one is running or the buffer is
+    /// full, returning its generation and the ids to exclude.
+    fn start(&mut self) -> Option<(usize, Vec<String>)> {
+        if self.in_flight || self.comparisons.len() >= self.depth {
+            return None;
+        }
+        self.in_flight = true;
+
+        Some((self.generation, self.exclude()))
+    }
+
+    /// Ends the prefetch started in `generation`, buffering its comparison
+    /// if it is still relevant. Returns whether it was buffered.
+    fn finish(
+        &mut self,
+        generation: usize,
+        comparison: Option<Comparison>,
+    ) -> bool {
+        if generation != self.generation {
+            return false;
+        }
+        self.in_flight = false;
+
+        match comparison {
+            Some(comparison)
+                if !self.served.contains(&comparison.id)
+                    && !self
+                        .comparisons
+                        .iter()
+                        .any(|buffered| buffered.id == comparison.id) =>
+            {
+                self.comparisons.push_back(comparison);
+                true
+            },
+            _ => false,
+        }
+    }
+}
+
+/// Serves the first buffered comparison the API confirms still accepts
+/// votes, fetching one when none is left or validity cannot be confirmed.
+pub(super) async fn next_comparison(
+    api: &Api,
+    prefetch: &Rc<RefCell<PrefetchBuffer>>,
+    user_id: String,
+    dirname: String,
+) -> Result<Comparison, RequestError> {
+    loop {
+        let Some(comparison) = prefetch.borrow_mut().pop() else {
+            break;
+        };
+        match api.comparison_is_valid(comparison.id.clone()).await {
+            Ok(true) => return Ok(comparison),
+            Ok(false) => continue,
+            // it may have been invalidated, so it is dropped for a fresh
+            // one
+            Err(error) => {
+                console_error!(error.to_string());
+                break;
+            },
+        }
+    }
+
+    let exclude = prefetch.borrow().exclude();
+    api.get_comparison_for_user(user_id, dirname, exclude).await
+}
+
+/// Fills the buffer in the background. Failures are only logged, the
+/// comparison is then fetched when it is needed.
+pub(super) fn prefetch_comparisons(
+    api: Api,
+    prefetch: Rc<RefCell<PrefetchBuffer>>,
+    user_id: String,
+    dirname: String,
+) {
+    wasm_bindgen_futures::spawn_local(async move {
+        loop {
+            let started = prefetch.borrow_mut().start();
+            let Some((generation, exclude)) = started else {
+                break;
+            };
+            let response = api
+                .get_comparison_for_user(
+                    user_id.clone(),
+                    dirname.clone(),
+                    exclude,
+                )
+                .await;
+            let comparison = match response {
+                Ok(comparison) => Some(comparison),
+                Err(error) => {
+                    console_error!(error.to_string());
+                    None
+                },
+            };
+            // stop when the API has nothing new to offer
+            if !prefetch.borrow_mut().finish(generation, comparison) {
+                break;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+
+    use super::PrefetchBuffer;
+    use crate::request::Comparison;
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn comparison(id: &str) -> Comparison {
+        Comparison {
+            id: id.to_string(),
+            ..Comparison::default()
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn prefetched_comparisons_are_served_in_order() {
+        let mut buffer = PrefetchBuffer::new(2);
+
+        let (generation, _) = buffer.start().expect("prefetch to start");
+        assert!(buffer.finish(generation, Some(comparison("a"))));
+        let (generation, _) = buffer.start().expect("prefetch to start");
+        assert!(buffer.finish(generation, Some(comparison("b"))));
+
+        assert!(buffer.pop() == Some(comparison("a")));
+        assert!(buffer.pop() == Some(comparison("b")));
+        assert!(buffer.pop().is_none());
+    }
+
+    #[wasm_bindgen_test]
+    fn prefetch_does_not_start_when_buffer_is_full() {
+        let mut buffer = PrefetchBuffer::new(1);
+
+        let (generation, _) = buffer.start().expect("prefetch to start");
+        buffer.finish(generation, Some(comparison("a")));
+
+        assert!(buffer.start().is_none());
+    }
+
+    #[wasm_bindgen_test]
+    fn only_one_prefetch_runs_at_a_time() {
+        let mut buffer = PrefetchBuffer::new(2);
+
+        assert!(buffer.start().is_some());
+        assert!(buffer.start().is_none());
+    }
+
+    #[wasm_bindgen_test]
+    fn served_comparisons_are_not_buffered_again() {
+        let mut buffer = PrefetchBuffer::new(1);
+        buffer.serve(&comparison("a"));
+
+        let (generation, exclude) = buffer.start().expect("prefetch to start");
+        assert_eq!(exclude, vec!["a".to_string()]);
+        assert!(!buffer.finish(generation, Some(comparison("a"))));
+        assert!(buffer.pop().is_none());
+    }
+
+    #[wasm_bindgen_test]
+    fn serving_a_buffered_comparison_removes_it() {
+        let mut buffer = PrefetchBuffer::new(2);
+
+        let (generation, _) = buffer.start().expect("prefetch to start");
+        buffer.finish(generation, Some(comparison("a")));
+        let (generation, _) = buffer.start().expect("prefetch to start");
+        buffer.finish(generation, Some(comparison("b")));
+        // fetched directly while it was being prefetched
+        buffer.serve(&comparison("a"));
+
+        assert!(buffer.pop() == Some(comparison("b")));
+        assert!(buffer.pop().is_none());
+    }
+
+    #[wasm_bindgen_test]
+    fn prefetches_from_before_a_clear_are_discarded() {
+        let mut buffer = PrefetchBuffer::new(1);
+
+        let (generation, _) = buffer.start().expect("prefetch to start");
+        buffer.clear();
+
+        assert!(!buffer.finish(generation, Some(comparison("a"))));
+        assert!(buffer.pop().is_none());
+    }
+}
@@ -0,0 +1,82 @@
+use yew::{
+    classes,
+    function_component,
+    html,
+    use_context,
+    Callback,
+    Html,
+    Properties,
+    UseReducerHandle,
+};
+
+use crate::{
+    pages::markdown_to_yew_html,
+    request::Presentation,
+    shared_components::Button,
+    Language,
+};
+
+#[derive(Properties, PartialEq)]
+pub(super) struct PresentationSelectProps {
+    pub(super) presentations: &'static [Presentation],
+    pub(super) selected: Presentation,
+    pub(super) onselect: Callback<Presentation>,
+}
+
+/// Lets the participant switch how the images are laid out.
+#[function_component(PresentationSelect)]
+pub(super) fn presentation_select(props: &PresentationSelectProps) -> Html {
+    let language = match use_context::<UseReducerHandle<Language>>() {
+        Some(ctx) => (*ctx).clone(),
+        None => Language::default(),
+    };
+
+    let buttons = props
+        .presentations
+        .iter()
+        .map(|presentation| {
+            let name = presentation.name();
+            let text = language.load_file(&format!("presentation_{name}.md"));
+            let text = markdown_to_yew_html(text.unwrap_or(""));
+            let selected = *presentation == props.selected;
+            let onclick = {
+                let onselect = props.onselect.clone();
+                let presentation = *presentation;
+                Callback::from(move |_| onselect.emit(presentation))
+            };
+
+            html! {
+                <Button
+                    id={format!("presentation_{name}_button")}
+                    class={classes![
+                        "border-2",
+                        "border-gray-800",
+                        "hover:text-gray-200",
+                        selected.then_some("bg-black/[0.2]"),
+                    ]}
+                    disabled={selected}
+                    onclick={onclick}
+                >
+                    { text }
+                </Button>
+            }
+        })
+        .collect::<Html>();
+
+    html! {
+        <section
+            id="presentation_select"
+            class={classes![
+                "self-center",
+                "flex",
+                "flex-row",
+                "justify-center",
+                "gap-1",
+                "md:gap-4",
+                "mt-2",
+            ]}
+        >
+            { buttons }
+        </section>
+    }
+}
@@ -4,6 +4,7 @@ use yew::{
     html,
     use_context,
     Html,
+    Properties,
     UseReducerHandle,
 };
 
@@ -12,28 +13,33 @@ use crate::{
     Language,
 };
 
+#[derive(Properties, PartialEq)]
+pub(super) struct PromptProps {
+    pub(super) name: &'static str,
+}
+
 #[function_component(Prompt)]
-pub(super) fn prompt() -> Html {
+pub(super) fn prompt(props: &PromptProps) -> Html {
     let language = match use_context::<UseReducerHandle<Language>>() {
         Some(ctx) => (*ctx).clone(),
         None => Language::default(),
     };
 
-    let which_is_best_prompt = language.load_file("which_is_best_prompt.md");
-    let which_is_best_prompt =
-        markdown_to_yew_html(which_is_best_prompt.unwrap_or(""));
+    let prompt = language.load_file(&format!("{}.md", props.name));
+    let prompt = markdown_to_yew_html(prompt.unwrap_or(""));
 
     html! {
         <section
-            id="which_is_best_prompt"
+            id={props.name}
             class={classes![
                 "self-center",
                 "pt-4",
                 "text-xl",
-                "text-gray-200"
+                "text-center",
+                "text-gray-200",
             ]}
         >
-            { which_is_best_prompt }
+            { prompt }
         </section>
     }
 }
@@ -46,6 +52,11 @@ mod tests {
         wasm_bindgen_test,
         wasm_bindgen_test_configure,
     };
+    use yew::{
+        function_component,
+        html,
+        Html,
+    };
 
     use super::Prompt;
     use crate::{
@@ -59,26 +70,22 @@ mod tests {
     };
     wasm_bindgen_test_configure!(run_in_browser);
 
-    #[wasm_bindgen_test]
-    fn which_is_best_prompt_markdown_exists() {
-        // add 1 to len to run even if no languages are available
-        for language_index in 0..AVAILABLE_LANGUAGES.len() + 1 {
-            let language = Language {
-                index: language_index,
-            };
-            let file = language.load_file("which_is_best_prompt.md");
-
-            assert!(file.is_some())
+    #[function_component(TestPrompt)]
+    fn test_prompt() -> Html {
+        html! {
+            <div>
+                <Prompt name={"which_is_best_prompt"} />
+            </div>
         }
     }
 
     #[wasm_bindgen_test]
-    async fn which_is_best_prompt_text_is_visible() {
+    async fn prompt_text_is_visible() {
         // add 1 to len to run even if no languages are available
         for language_index in 0..AVAILABLE_LANGUAGES.len() + 1 {
             DEFAULT_LANGUAGE.store(language_index, Ordering::SeqCst);
 
-            render_yew_component!(Prompt);
+            render_yew_component!(TestPrompt);
             wasm_sleep_in_ms(50).await;
 
             let language = Language::default();
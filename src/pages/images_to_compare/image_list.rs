@@ -1,28 +1,175 @@
+use std::collections::HashMap;
+
 use yew::{
     classes,
     function_component,
     html,
+    use_mut_ref,
+    use_state_eq,
     Callback,
     Html,
+    PointerEvent,
     Properties,
+    WheelEvent,
 };
 
+use super::view::{
+    View,
+    ViewAction,
+};
 use crate::shared_components::{
+    frame_position,
     Button,
     Loading,
+    Loupe,
 };
 
+/// Pointer movement, in CSS pixels, past which a press is a drag rather
+/// than a click on the image.
+const DRAG_THRESHOLD: f64 = 4.0;
+
 #[derive(Properties, PartialEq)]
 pub(super) struct ImageListProps {
     pub(super) loading: bool,
     pub(super) images: Vec<String>,
     pub(super) onclick: Callback<String>,
+    pub(super) view: View,
+    pub(super) onviewaction: Callback<ViewAction>,
+    /// Magnification of the loupe following the pointer, while it is on.
+    #[prop_or_default]
+    pub(super) loupe: Option<f64>,
+    /// Keeps the images from being chosen, e.g. within the minimum viewing
+    /// time, while they can still be zoomed and panned.
+    #[prop_or_default]
+    pub(super) disabled: bool,
+}
+
+#[derive(Default)]
+struct Pointers {
+    /// Last position of each pointer pressed on an image, in CSS pixels.
+    positions: HashMap<i32, (f64, f64)>,
+    /// Distance moved since the first pointer was pressed.
+    moved: f64,
 }
 
 #[function_component(ImageList)]
 pub(super) fn image_list(props: &ImageListProps) -> Html {
+    let pointers = use_mut_ref(Pointers::default);
+    // shared by all the images, so each loupe shows the same region
+    let loupe_position = use_state_eq(|| None::<(f64, f64)>);
+    let loupe_on = props.loupe.is_some();
     let onclick = props.onclick.clone();
 
+    let on_wheel = {
+        let onviewaction = props.onviewaction.clone();
+        Callback::from(move |event: WheelEvent| {
+            if let Some(((x, y), _)) = frame_position(&event) {
+                // zoom the images rather than scroll the page
+                event.prevent_default();
+                let factor = (-event.delta_y() * 0.002).exp();
+                onviewaction.emit(ViewAction::Zoom { factor, x, y });
+            }
+        })
+    };
+
+    let on_pointer_down = {
+        let pointers = pointers.clone();
+        let loupe_position = loupe_position.clone();
+        Callback::from(move |event: PointerEvent| {
+            if loupe_on {
+                loupe_position.set(frame_position(&event).map(|(at, _)| at));
+            }
+            let mut pointers = pointers.borrow_mut();
+            if pointers.positions.is_empty() {
+                pointers.moved = 0.0;
+            }
+            pointers.positions.insert(
+                event.pointer_id(),
+                (f64::from(event.client_x()), f64::from(event.client_y())),
+            );
+        })
+    };
+
+    let on_pointer_move = {
+        let pointers = pointers.clone();
+        let onviewaction = props.onviewaction.clone();
+        let loupe_position = loupe_position.clone();
+        Callback::from(move |event: PointerEvent| {
+            let Some(((x, y), (width, height))) = frame_position(&event) else {
+                return;
+            };
+            if loupe_on {
+                loupe_position.set(Some((x, y)));
+            }
+            let mut pointers = pointers.borrow_mut();
+            let Some(&(last_x, last_y)) =
+                pointers.positions.get(&event.pointer_id())
+            else {
+                return;
+            };
+            let (client_x, client_y) =
+                (f64::from(event.client_x()), f64::from(event.client_y()));
+            let (dx, dy) = (client_x - last_x, client_y - last_y);
+            pointers.moved += dx.hypot(dy);
+
+            match pointers.positions.len() {
+                // dragging moves the loupe rather than the images
+                _ if loupe_on => {},
+                1 => onviewaction.emit(ViewAction::Pan {
+                    dx: dx / width,
+                    dy: dy / height,
+                }),
+                2 => {
+                    // pinch: zoom by how much the pointers moved apart
+                    let other = pointers
+                        .positions
+                        .iter()
+                        .find(|(id, _)| **id != event.pointer_id())
+                        .map(|(_, position)| *position);
+                    if let Some((other_x, other_y)) = other {
+                        let before = (last_x - other_x).hypot(last_y - other_y);
+                        let after =
+                            (client_x - other_x).hypot(client_y - other_y);
+                        if before > 0.0 {
+                            onviewaction.emit(ViewAction::Zoom {
+                                factor: after / before,
+                                x,
+                                y,
+                            });
+                        }
+                    }
+                },
+                _ => {},
+            }
+            pointers
+                .positions
+                .insert(event.pointer_id(), (client_x, client_y));
+        })
+    };
+
+    let on_pointer_up = {
+        let pointers = pointers.clone();
+        let loupe_position = loupe_position.clone();
+        Callback::from(move |event: PointerEvent| {
+            pointers.borrow_mut().positions.remove(&event.pointer_id());
+            // a touch loupe only shows while the finger is down
+            if event.pointer_type() == "touch" {
+                loupe_position.set(None);
+            }
+        })
+    };
+
+    let on_pointer_leave = {
+        let pointers = pointers.clone();
+        let loupe_position = loupe_position.clone();
+        Callback::from(move |event: PointerEvent| {
+            pointers.borrow_mut().positions.remove(&event.pointer_id());
+            loupe_position.set(None);
+        })
+    };
+
+    let loupe = props.loupe.zip(*loupe_position);
+
     props
         .images
         .iter()
@@ -30,8 +177,18 @@ pub(super) fn image_list(props: &ImageListProps) -> Html {
         .map(|(index, image)| {
             let on_image_select = {
                 let onclick = onclick.clone();
+                let pointers = pointers.clone();
                 let image = image.clone();
-                Callback::from(move |_| onclick.emit(image.clone()))
+                let disabled = props.disabled;
+                Callback::from(move |_| {
+                    let dragged = pointers.borrow().moved > DRAG_THRESHOLD;
+                    pointers.borrow_mut().moved = 0.0;
+                    // the press was used to pan or pinch the images
+                    if dragged || disabled {
+                        return;
+                    }
+                    onclick.emit(image.clone())
+                })
             };
 
             if props.loading {
@@ -61,12 +218,48 @@ pub(super) fn image_list(props: &ImageListProps) -> Html {
                         ]}
                         onclick={on_image_select}
                     >
-                        <img
-                            id={format!("image_to_compare_{index}")}
-                            class={classes!["h-full"]}
-                            src={image.clone()}
-                            alt=""
-                        />
+                        <div
+                            id={format!("image_frame_{index}")}
+                            class={classes![
+                                "relative",
+                                "h-full",
+                                "aspect-square",
+                                "overflow-hidden",
+                                "touch-none",
+                            ]}
+                            onwheel={on_wheel.clone()}
+                            onpointerdown={on_pointer_down.clone()}
+                            onpointermove={on_pointer_move.clone()}
+                            onpointerup={on_pointer_up.clone()}
+                            onpointercancel={on_pointer_up.clone()}
+                            onpointerleave={on_pointer_leave.clone()}
+                        >
+                            <img
+                                id={format!("image_to_compare_{index}")}
+                                class={classes![
+                                    "h-full",
+                                    "w-full",
+                                    "object-contain",
+                                ]}
+                                style={props.view.style()}
+                                src={image.clone()}
+                                alt=""
+                                draggable="false"
+                            />
+                            if let Some((magnification, (x, y))) = loupe {
+                                <Loupe
+                                    id={format!("loupe_{index}")}
+                                    image={image.clone()}
+                                    x={x}
+                                    y={y}
+                                    image_style={props.view.loupe_style(
+                                        x,
+                                        y,
+                                        magnification,
+                                    )}
+                                />
+                            }
+                        </div>
                     </Button>
                 }
             }
@@ -1,18 +1,14 @@
-mod change_user_modal;
-mod dot_button;
-mod finish_comparing_modal;
-mod header;
 mod image_list;
-mod instructions_card;
-mod instructions_modal;
-mod prompt;
+mod view;
 
+use wasm_bindgen::JsCast;
 use yew::{
     classes,
     function_component,
     html,
     use_context,
     use_effect_with,
+    use_reducer_eq,
     use_state_eq,
     Callback,
     Html,
@@ -20,169 +16,213 @@ use yew::{
 };
 
 use self::{
-    header::Header,
     image_list::ImageList,
-    instructions_modal::InstructionsModal,
-    prompt::Prompt,
+    view::{
+        pixel_exact_zoom,
+        View,
+        ViewAction,
+    },
 };
 use crate::{
-    assets::QuestionMarkCircle,
-    pages::markdown_to_yew_html,
+    dom::DOM,
+    pages::{
+        experiment::{
+            Experiment,
+            ExperimentPage,
+            ExposureMask,
+            StimuliProps,
+        },
+        markdown_to_yew_html,
+    },
     request::{
-        get_comparison_for_user,
-        get_user,
-        post_vote,
-        Comparison,
-        User,
-        Vote,
+        Presentation,
         VoteValue,
     },
     shared_components::{
+        image_label,
         Button,
-        FatalErrorModal,
-        Footer,
+        Flicker,
+        ReferenceImage,
+        Wipe,
     },
     Language,
 };
 
-#[function_component(ImagesToCompare)]
-pub(crate) fn images_to_compare() -> Html {
+/// Two-alternative forced choice: the participant picks the best image.
+pub(crate) struct WhichIsBest;
+
+impl Experiment for WhichIsBest {
+    type Stimuli = WhichIsBestStimuli;
+
+    const LOUPE_MAGNIFICATION: Option<f64> = Some(3.0);
+    const PRESENTATIONS: &'static [Presentation] = &[
+        Presentation::SideBySide,
+        Presentation::Wipe,
+        Presentation::Flicker,
+    ];
+    const PROMPT: &'static str = "which_is_best_prompt";
+}
+
+pub(crate) type ImagesToCompare = ExperimentPage<WhichIsBest>;
+
+#[function_component(WhichIsBestStimuli)]
+pub(crate) fn which_is_best_stimuli(props: &StimuliProps) -> Html {
     let language = match use_context::<UseReducerHandle<Language>>() {
         Some(ctx) => (*ctx).clone(),
         None => Language::default(),
     };
-    let loading = use_state_eq(|| true);
-    let show_fatal_error_modal = use_state_eq(|| false);
-    let show_instructions_modal = use_state_eq(|| false);
-    let comparison_state = use_state_eq(|| None::<Comparison>);
-    let user_state = use_state_eq(|| User::default());
-
-    let instructions_button_sr =
-        language.load_file("instructions_button_sr.md");
-    let instructions_button_sr =
-        markdown_to_yew_html(instructions_button_sr.unwrap_or(""));
-
-    let reload = {
-        let loading = loading.clone();
-        let comparison_state = comparison_state.clone();
-        Callback::from(move |_| {
-            loading.set(true);
-            comparison_state.set(None);
-        })
+    let view = use_reducer_eq(View::default);
+    let loupe_on = use_state_eq(|| false);
+
+    {
+        let view = view.clone();
+        use_effect_with(
+            (props.images.clone(), props.presentation),
+            move |_| view.dispatch(ViewAction::Reset),
+        );
+    }
+
+    {
+        let onviewport = props.onviewport.clone();
+        use_effect_with(*view, move |view| onviewport.emit(view.viewport()));
+    }
+
+    let on_view_action = {
+        let view = view.clone();
+        Callback::from(move |action| view.dispatch(action))
     };
 
-    let close_fatal_error_modal = {
-        let show_fatal_error_modal = show_fatal_error_modal.clone();
+    let toggle_pixel_exact = {
+        let view = view.clone();
         Callback::from(move |_| {
-            show_fatal_error_modal.set(false);
+            if view.is_pixel_exact() {
+                view.dispatch(ViewAction::Reset);
+                return;
+            }
+            // all images are shown in same-sized frames, so the first one
+            // gives the zoom for all of them
+            let Some(image) = DOM::get_element_by_id("image_to_compare_0")
+                .and_then(|image| {
+                    image.dyn_into::<web_sys::HtmlImageElement>().ok()
+                })
+            else {
+                return;
+            };
+            let zoom = pixel_exact_zoom(
+                (
+                    f64::from(image.natural_width()),
+                    f64::from(image.natural_height()),
+                ),
+                (
+                    f64::from(image.client_width()),
+                    f64::from(image.client_height()),
+                ),
+                DOM::device_pixel_ratio(),
+            );
+            if let Some(zoom) = zoom {
+                view.dispatch(ViewAction::PixelExact { zoom });
+            }
         })
     };
 
-    let open_instructions_modal = {
-        let show_instructions_modal = show_instructions_modal.clone();
-        Callback::from(move |_| {
-            show_instructions_modal.set(true);
-        })
+    let reset_zoom = {
+        let view = view.clone();
+        Callback::from(move |_| view.dispatch(ViewAction::Reset))
     };
 
-    let close_instructions_modal = {
-        let show_instructions_modal = show_instructions_modal.clone();
-        Callback::from(move |_| {
-            show_instructions_modal.set(false);
-        })
+    let toggle_loupe = {
+        let loupe_on = loupe_on.clone();
+        Callback::from(move |_| loupe_on.set(!*loupe_on))
     };
 
-    let on_image_select = {
-        let loading = loading.clone();
-        let show_fatal_error_modal = show_fatal_error_modal.clone();
-        let comparison_state = comparison_state.clone();
-        let user_state = user_state.clone();
+    let pixel_exact_button = language.load_file("pixel_exact_button.md");
+    let pixel_exact_button =
+        markdown_to_yew_html(pixel_exact_button.unwrap_or(""));
+
+    let zoom_reset_button = language.load_file("zoom_reset_button.md");
+    let zoom_reset_button =
+        markdown_to_yew_html(zoom_reset_button.unwrap_or(""));
+
+    let loupe_button = language.load_file("loupe_button.md");
+    let loupe_button = markdown_to_yew_html(loupe_button.unwrap_or(""));
 
+    let on_image_select = {
+        let onvote = props.onvote.clone();
         Callback::from(move |image: String| {
-            loading.set(true);
-            let show_fatal_error_modal = show_fatal_error_modal.clone();
-            let comparison_state = comparison_state.clone();
-            let user_state = user_state.clone();
-            wasm_bindgen_futures::spawn_local(async move {
-                let vote = Vote::build(
-                    (*comparison_state)
-                        .clone()
-                        .expect("BUG: Comparison expected"),
-                )
-                .user(user_state.id.clone())
-                .vote(VoteValue::OneIsBetter(image));
-                let response = post_vote(vote).await;
-                match response {
-                    Ok(_) => comparison_state.set(None),
-                    Err(_) => show_fatal_error_modal.set(true),
-                }
-            });
+            onvote.emit(VoteValue::OneIsBetter(image))
         })
     };
 
-    let fetch_comparison = {
-        let loading = loading.clone();
-        let show_fatal_error_modal = show_fatal_error_modal.clone();
-        let show_instructions_modal = show_instructions_modal.clone();
-        let comparison_state = comparison_state.clone();
-        let user_state = user_state.clone();
-
-        || {
-            if *loading && !*show_fatal_error_modal {
-                wasm_bindgen_futures::spawn_local(async move {
-                    let user_response = get_user().await;
-                    let comparison_response = match user_response {
-                        Ok(ref user) => {
-                            get_comparison_for_user(
-                                user.id.clone(),
-                                String::default(),
-                            )
-                            .await
-                        },
-                        Err(_) => Err(()),
-                    };
-                    match (user_response, comparison_response) {
-                        (Ok(user), Ok(comparison)) => {
-                            loading.set(false);
-                            if user.votes == 0 {
-                                show_instructions_modal.set(true);
-                            }
-                            user_state.set(user);
-                            comparison_state.set(Some(comparison));
-                        },
-                        (_, _) => {
-                            show_fatal_error_modal.set(true);
-                        },
-                    }
-                });
-            }
-        }
-    };
+    // overlaid images are chosen by their label rather than by clicking them
+    let choose_image_buttons = props
+        .images
+        .iter()
+        .enumerate()
+        .map(|(index, image)| {
+            let text = language.load_file("choose_image_button.md");
+            let text =
+                text.unwrap_or("").replace("{label}", &image_label(index));
+            let text = markdown_to_yew_html(&text);
+            let onclick = {
+                let on_image_select = on_image_select.clone();
+                let image = image.clone();
+                Callback::from(move |_| on_image_select.emit(image.clone()))
+            };
 
-    {
-        let comparison_state = comparison_state.clone();
+            html! {
+                <Button
+                    id={format!("choose_image_button_{index}")}
+                    class={classes![
+                        "border-2",
+                        "border-gray-800",
+                        "hover:text-gray-200",
+                        "w-full",
+                    ]}
+                    disabled={props.answer_disabled()}
+                    onclick={onclick}
+                >
+                    { text }
+                </Button>
+            }
+        })
+        .collect::<Html>();
 
-        use_effect_with(comparison_state, move |_| fetch_comparison());
-    }
+    // zoomed with the images next to it
+    let reference_style = match props.presentation {
+        Presentation::SideBySide => view.style(),
+        _ => String::new(),
+    };
+    let reference = props.reference.clone().filter(|_| !props.loading);
 
-    let image_list_to_display = match (*comparison_state).clone() {
-        Some(comparison) => comparison.images,
-        None => Comparison::default().images,
+    let images = match (props.presentation, props.loading) {
+        (Presentation::Wipe, false) => html! {
+            <Wipe images={props.images.clone()} />
+        },
+        (Presentation::Flicker, false) => html! {
+            <Flicker
+                images={props.images.clone()}
+                interval={props.flicker_interval}
+            />
+        },
+        _ => html! {
+            <ImageList
+                loading={props.loading}
+                images={props.images.clone()}
+                onclick={on_image_select}
+                view={*view}
+                onviewaction={on_view_action}
+                loupe={props.loupe_magnification.filter(|_| *loupe_on)}
+                disabled={props.answer_disabled()}
+            />
+        },
     };
 
     html! {
-        <section
-            id="compare"
-            class={classes!["h-full", "flex", "flex-col"]}
-        >
-            <Header
-                user={(*user_state).clone()}
-                onreload={reload}
-            />
-            <Prompt />
+        <>
             <section
                 id="images_list"
                 class={classes![
+                    "relative",
                     "flex-1",
                     "overflow-hidden",
                     "flex",
@@ -194,41 +234,102 @@ pub(crate) fn images_to_compare() -> Html {
                     "md:gap-4",
                 ]}
             >
-                <ImageList
-                    loading={(*loading).clone()}
-                    images={image_list_to_display}
-                    onclick={on_image_select}
-                />
+                if let Some(reference) = reference {
+                    <ReferenceImage image={reference} style={reference_style} />
+                }
+                { images }
+                <ExposureMask phase={props.exposure} />
             </section>
-            <Footer>
-                <Button
-                    id={"open_instructions_modal_button"}
-                    onclick={open_instructions_modal}
+            if props.presentation != Presentation::SideBySide {
+                <section
+                    id="choose_image_buttons"
+                    class={classes![
+                        "self-center",
+                        "flex",
+                        "flex-row",
+                        "w-1/2",
+                        "items-stretch",
+                        "justify-center",
+                        "gap-1",
+                        "md:gap-4",
+                        "my-4",
+                        "md:mt-0",
+                    ]}
+                >
+                    { choose_image_buttons }
+                </section>
+            } else {
+                <section
+                    id="zoom_controls"
+                    class={classes![
+                        "self-center",
+                        "flex",
+                        "flex-row",
+                        "items-center",
+                        "justify-center",
+                        "gap-1",
+                        "md:gap-4",
+                        "my-2",
+                    ]}
                 >
-                    <QuestionMarkCircle
+                    <Button
+                        id="pixel_exact_button"
                         class={classes![
-                            "h-8",
-                            "stroke-gray-100",
+                            "border-2",
+                            "border-gray-800",
+                            "hover:text-gray-200",
                         ]}
-                    />
-                    <span class={classes!["sr-only"]}>
-                        { instructions_button_sr }
+                        disabled={props.loading}
+                        onclick={toggle_pixel_exact}
+                    >
+                        { pixel_exact_button }
+                    </Button>
+                    <span
+                        id="zoom_level"
+                        class={classes![
+                            "w-16",
+                            "text-center",
+                            "text-gray-600",
+                        ]}
+                    >
+                        { format!("{:.0}%", view.zoom() * 100.0) }
                     </span>
-                </Button>
-            </Footer>
-            if *show_instructions_modal && !*show_fatal_error_modal {
-                <InstructionsModal onclose={close_instructions_modal} />
-            }
-            if *show_fatal_error_modal {
-                <FatalErrorModal onclose={close_fatal_error_modal} />
+                    <Button
+                        id="zoom_reset_button"
+                        class={classes![
+                            "border-2",
+                            "border-gray-800",
+                            "hover:text-gray-200",
+                        ]}
+                        disabled={props.loading}
+                        onclick={reset_zoom}
+                    >
+                        { zoom_reset_button }
+                    </Button>
+                    if props.loupe_magnification.is_some() {
+                        <Button
+                            id="loupe_button"
+                            class={classes![
+                                "border-2",
+                                "border-gray-800",
+                                "hover:text-gray-200",
+                                loupe_on.then_some("bg-black/[0.2]"),
+                            ]}
+                            disabled={props.loading}
+                            onclick={toggle_loupe}
+                        >
+                            { loupe_button }
+                        </Button>
+                    }
+                </section>
             }
-        </section>
+        </>
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::sync::atomic::Ordering;
+    use std::time::Duration;
 
     use wasm_bindgen::JsCast;
     use wasm_bindgen_test::{
@@ -236,296 +337,114 @@ mod tests {
         wasm_bindgen_test_configure,
     };
 
-    use super::ImagesToCompare;
+    use super::{
+        ImagesToCompare,
+        WhichIsBestStimuli,
+    };
     use crate::{
+        click_button,
         dom::DOM,
-        markdown_to_decoded_html,
+        pages::experiment::{
+            Experiment,
+            ExperimentPage,
+        },
         render_yew_component,
         request::{
-            get_user,
-            images::GET_IMAGES_RETURNS_OK,
-            user::{
-                GET_USER_RETURNS_OK,
-                VOTES_TO_DISPLAY,
-            },
+            Comparison,
+            FakeClient,
+            Presentation,
+            VoteValue,
         },
         wasm_sleep_in_ms,
         Language,
         AVAILABLE_LANGUAGES,
-        DEFAULT_LANGUAGE,
+        WIDE_IMAGE,
     };
 
     wasm_bindgen_test_configure!(run_in_browser);
 
-    #[wasm_bindgen_test]
-    fn change_user_button_markdown_exists() {
-        // add 1 to len to run even if no languages are available
-        for language_index in 0..AVAILABLE_LANGUAGES.len() + 1 {
-            let language = Language {
-                index: language_index,
-            };
-            let file = language.load_file("change_user_button.md");
-
-            assert!(file.is_some())
-        }
-    }
-
-    #[wasm_bindgen_test]
-    async fn button_to_change_user_exists() {
-        // add 1 to len to run even if no languages are available
-        for language_index in 0..AVAILABLE_LANGUAGES.len() + 1 {
-            DEFAULT_LANGUAGE.store(language_index, Ordering::SeqCst);
-
-            render_yew_component!(ImagesToCompare);
-            wasm_sleep_in_ms(150).await;
-
-            let language = Language::default();
-            let expected = language.load_file("change_user_button.md");
-            let expected = markdown_to_decoded_html(expected.unwrap_or(""));
-
-            assert!(DOM::has_button_with_inner_html(&expected));
-        }
-    }
+    struct DwellingWhichIsBest;
 
-    #[wasm_bindgen_test]
-    async fn change_user_modal_is_closed_by_default() {
-        render_yew_component!(ImagesToCompare);
-        wasm_sleep_in_ms(150).await;
+    impl Experiment for DwellingWhichIsBest {
+        type Stimuli = WhichIsBestStimuli;
 
-        assert!(DOM::get_element_by_id("change_user_modal").is_none());
+        const MIN_VIEWING_TIME: Option<Duration> =
+            Some(Duration::from_millis(200));
+        const PROMPT: &'static str = "which_is_best_prompt";
     }
 
-    #[wasm_bindgen_test]
-    async fn button_to_change_user_shows_change_user_modal() {
-        render_yew_component!(ImagesToCompare);
-        wasm_sleep_in_ms(150).await;
-
-        let button = DOM::get_button_by_id("change_user_button")
-            .expect("Element #change_user_button to be present")
-            .dyn_into::<web_sys::HtmlElement>()
-            .expect("Element to be castable to HtmlElement");
-
-        button.click();
-        wasm_sleep_in_ms(50).await; // allow page to re-render
-        assert!(DOM::get_element_by_id("change_user_modal").is_some());
-    }
+    type DwellingWhichIsBestPage = ExperimentPage<DwellingWhichIsBest>;
 
     #[wasm_bindgen_test]
-    async fn change_user_modal_can_be_closed() {
-        render_yew_component!(ImagesToCompare);
-        wasm_sleep_in_ms(150).await;
-
-        let open_button = DOM::get_button_by_id("change_user_button")
-            .expect("Element #change_user_button to be present")
-            .dyn_into::<web_sys::HtmlElement>()
-            .expect("Element to be castable to HtmlElement");
-
-        open_button.click();
-        wasm_sleep_in_ms(50).await; // allow page to re-render
-
-        let close_button =
-            DOM::get_button_by_id("close_change_user_modal_button")
-                .expect("Element #close_change_user_modal_button to be present")
-                .dyn_into::<web_sys::HtmlElement>()
-                .expect("Element to be castable to HtmlElement");
-
-        close_button.click();
-        wasm_sleep_in_ms(50).await; // allow page to re-render
-        assert!(DOM::get_element_by_id("change_user_modal").is_none());
-    }
-
-    #[wasm_bindgen_test]
-    async fn change_user_modal_can_be_closed_by_cancel_button() {
-        render_yew_component!(ImagesToCompare);
-        wasm_sleep_in_ms(150).await;
-
-        let open_button = DOM::get_button_by_id("change_user_button")
-            .expect("Element #change_user_button to be present")
-            .dyn_into::<web_sys::HtmlElement>()
-            .expect("Element to be castable to HtmlElement");
-
-        open_button.click();
-        wasm_sleep_in_ms(50).await; // allow page to re-render
-
-        let close_button = DOM::get_button_by_id("change_user_cancel_button")
-            .expect("Element #change_user_cancel_button to be present")
-            .dyn_into::<web_sys::HtmlElement>()
-            .expect("Element to be castable to HtmlElement");
-
-        close_button.click();
-        wasm_sleep_in_ms(50).await; // allow page to re-render
-        assert!(DOM::get_element_by_id("change_user_modal").is_none());
-    }
-
-    #[wasm_bindgen_test]
-    async fn confirm_reset_user_in_change_user_modal_reloads_page() {
-        GET_IMAGES_RETURNS_OK.store(true, Ordering::SeqCst);
-        GET_USER_RETURNS_OK.store(true, Ordering::SeqCst);
-        DOM::local_storage()
-            .unwrap()
-            .set_item("user_id", "123456")
-            .unwrap();
-        VOTES_TO_DISPLAY.store(rand::random(), Ordering::SeqCst);
-
-        render_yew_component!(ImagesToCompare);
-        wasm_sleep_in_ms(150).await;
-
-        let open_button = DOM::get_button_by_id("change_user_button")
-            .expect("Element #change_user_button to be present")
-            .dyn_into::<web_sys::HtmlElement>()
-            .expect("Element to be castable to HtmlElement");
-
-        open_button.click();
-        wasm_sleep_in_ms(50).await; // allow page to re-render
-
-        let confirm_button =
-            DOM::get_button_by_id("change_user_confirm_button")
-                .expect("Element #change_user_confirm_button to be present")
-                .dyn_into::<web_sys::HtmlElement>()
-                .expect("Element to be castable to HtmlElement");
-
-        confirm_button.click();
-        wasm_sleep_in_ms(150).await; // allow page to re-render
-        assert!(DOM::get_element_by_id("instructions_modal").is_some());
-    }
-
-    #[wasm_bindgen_test]
-    async fn button_to_finish_comparing_exists() {
-        render_yew_component!(ImagesToCompare);
-        wasm_sleep_in_ms(150).await;
-
-        assert!(DOM::get_button_by_id("finish_comparing_button").is_some());
-    }
-
-    #[wasm_bindgen_test]
-    fn finish_comparing_button_markdown_exists() {
+    fn which_is_best_prompt_markdown_exists() {
         // add 1 to len to run even if no languages are available
         for language_index in 0..AVAILABLE_LANGUAGES.len() + 1 {
             let language = Language {
                 index: language_index,
             };
-            let file = language.load_file("finish_comparing_button.md");
+            let file = language.load_file("which_is_best_prompt.md");
 
             assert!(file.is_some())
         }
     }
 
     #[wasm_bindgen_test]
-    async fn button_to_finish_comparing_shows_user_votes() {
-        GET_IMAGES_RETURNS_OK.store(true, Ordering::SeqCst);
-        GET_USER_RETURNS_OK.store(true, Ordering::SeqCst);
-        DOM::local_storage()
-            .unwrap()
-            .set_item("user_id", "123456")
-            .unwrap();
-        VOTES_TO_DISPLAY.store(rand::random(), Ordering::SeqCst);
-
+    fn zoom_controls_markdown_exists() {
         // add 1 to len to run even if no languages are available
         for language_index in 0..AVAILABLE_LANGUAGES.len() + 1 {
-            DEFAULT_LANGUAGE.store(language_index, Ordering::SeqCst);
-
-            render_yew_component!(ImagesToCompare);
-            wasm_sleep_in_ms(150).await;
-
-            let user = get_user().await.expect("request to return Ok response");
-
-            let language = Language::default();
-            let expected = language.load_file("finish_comparing_button.md");
-            let expected = expected
-                .unwrap_or("")
-                .replace("{votes}", &user.votes.to_string());
-            let expected = markdown_to_decoded_html(&expected);
-
-            let button = DOM::get_button_by_id("finish_comparing_button")
-                .expect("finish_comparing_button to be present");
+            let language = Language {
+                index: language_index,
+            };
 
-            assert_eq!(button.inner_html(), expected);
+            assert!(language.load_file("pixel_exact_button.md").is_some());
+            assert!(language.load_file("zoom_reset_button.md").is_some());
+            assert!(language.load_file("loupe_button.md").is_some());
         }
     }
 
     #[wasm_bindgen_test]
-    async fn finish_comparing_modal_is_closed_by_default() {
+    async fn images_start_unzoomed() {
         render_yew_component!(ImagesToCompare);
         wasm_sleep_in_ms(150).await;
 
-        assert!(DOM::get_element_by_id("finish_comparing_modal").is_none());
+        let zoom_level = DOM::get_element_by_id("zoom_level")
+            .expect("Element #zoom_level to be present")
+            .inner_html();
+        assert_eq!(zoom_level, "100%");
     }
 
     #[wasm_bindgen_test]
-    async fn button_to_finish_comparing_shows_change_user_modal() {
-        render_yew_component!(ImagesToCompare);
-        wasm_sleep_in_ms(150).await;
-
-        let button = DOM::get_button_by_id("finish_comparing_button")
-            .expect("Element #finish_comparing_button to be present")
-            .dyn_into::<web_sys::HtmlElement>()
-            .expect("Element to be castable to HtmlElement");
-
-        button.click();
-        wasm_sleep_in_ms(50).await; // allow page to re-render
-        assert!(DOM::get_element_by_id("finish_comparing_modal").is_some());
-    }
+    async fn vote_carries_viewport() {
+        let api = FakeClient::default();
 
-    #[wasm_bindgen_test]
-    async fn finish_comparing_modal_can_be_closed() {
-        render_yew_component!(ImagesToCompare);
+        render_yew_component!(ImagesToCompare, api.clone());
         wasm_sleep_in_ms(150).await;
 
-        let open_button = DOM::get_button_by_id("finish_comparing_button")
-            .expect("Element #finish_comparing_button to be present")
+        DOM::get_images_by_id_contains("image_to_compare_")
+            .expect("Images to compare to be present")[0]
+            .clone()
             .dyn_into::<web_sys::HtmlElement>()
-            .expect("Element to be castable to HtmlElement");
-
-        open_button.click();
-        wasm_sleep_in_ms(50).await; // allow page to re-render
-
-        let close_button =
-            DOM::get_button_by_id("close_finish_comparing_modal_button")
-                .expect(
-                    "Element #close_finish_comparing_modal_button to be \
-                     present",
-                )
-                .dyn_into::<web_sys::HtmlElement>()
-                .expect("Element to be castable to HtmlElement");
-
-        close_button.click();
-        wasm_sleep_in_ms(50).await; // allow page to re-render
-        assert!(DOM::get_element_by_id("finish_comparing_modal").is_none());
-    }
-
-    #[wasm_bindgen_test]
-    async fn show_fatal_error_modal_when_get_user_returns_error() {
-        GET_IMAGES_RETURNS_OK.store(true, Ordering::SeqCst);
-        GET_USER_RETURNS_OK.store(false, Ordering::SeqCst);
-
-        render_yew_component!(ImagesToCompare);
-        wasm_sleep_in_ms(150).await;
-
-        assert!(DOM::get_element_by_id("fatal_error_modal").is_some());
-    }
-
-    #[wasm_bindgen_test()]
-    async fn show_fatal_error_modal_when_get_images_returns_error() {
-        GET_IMAGES_RETURNS_OK.store(false, Ordering::SeqCst);
-        GET_USER_RETURNS_OK.store(true, Ordering::SeqCst);
-
-        render_yew_component!(ImagesToCompare);
-        wasm_sleep_in_ms(150).await;
-
-        assert!(DOM::get_element_by_id("fatal_error_modal").is_some());
+            .expect("Element to be castable to HtmlElement")
+            .click();
+        wasm_sleep_in_ms(50).await; // allow vote to be posted
+
+        let votes = api.posted_votes();
+        assert_eq!(votes.len(), 1);
+        let viewport = votes[0]
+            .recorded_viewport()
+            .expect("Vote to carry the viewport");
+        assert_eq!(viewport.zoom, 1.0);
+        assert!(!viewport.pixel_exact);
     }
 
     #[wasm_bindgen_test]
     async fn two_images_to_compare_exist() {
-        GET_IMAGES_RETURNS_OK.store(true, Ordering::SeqCst);
-        GET_USER_RETURNS_OK.store(true, Ordering::SeqCst);
-
         render_yew_component!(ImagesToCompare);
         wasm_sleep_in_ms(150).await;
 
         assert_eq!(
             DOM::get_images_by_id_contains("image_to_compare_")
-                .unwrap_or(vec![])
+                .unwrap_or_default()
                 .len(),
             2
         );
@@ -533,9 +452,6 @@ mod tests {
 
     #[wasm_bindgen_test]
     async fn choosing_first_image_loads_new_images() {
-        GET_IMAGES_RETURNS_OK.store(true, Ordering::SeqCst);
-        GET_USER_RETURNS_OK.store(true, Ordering::SeqCst);
-
         render_yew_component!(ImagesToCompare);
         wasm_sleep_in_ms(150).await;
 
@@ -552,14 +468,14 @@ mod tests {
         assert!(DOM::get_images_by_id_contains("image_to_compare_").is_none());
         assert_eq!(
             DOM::get_buttons_by_id_contains("loading_status_button_")
-                .unwrap_or(vec![])
+                .unwrap_or_default()
                 .len(),
             2
         );
         wasm_sleep_in_ms(100).await; // allow images to actually load
         assert_eq!(
             DOM::get_images_by_id_contains("image_to_compare")
-                .unwrap_or(vec![])
+                .unwrap_or_default()
                 .len(),
             2
         );
@@ -567,9 +483,6 @@ mod tests {
 
     #[wasm_bindgen_test]
     async fn choosing_second_image_loads_new_images() {
-        GET_IMAGES_RETURNS_OK.store(true, Ordering::SeqCst);
-        GET_USER_RETURNS_OK.store(true, Ordering::SeqCst);
-
         render_yew_component!(ImagesToCompare);
         wasm_sleep_in_ms(150).await;
 
@@ -586,139 +499,259 @@ mod tests {
         assert!(DOM::get_images_by_id_contains("image_to_compare_").is_none());
         assert_eq!(
             DOM::get_buttons_by_id_contains("loading_status_button_")
-                .unwrap_or(vec![])
+                .unwrap_or_default()
                 .len(),
             2
         );
         wasm_sleep_in_ms(100).await; // allow images to actually load
         assert_eq!(
             DOM::get_images_by_id_contains("image_to_compare_")
-                .unwrap_or(vec![])
+                .unwrap_or_default()
                 .len(),
             2
         );
     }
 
     #[wasm_bindgen_test]
-    async fn when_user_has_0_votes_show_instructions_modal() {
-        GET_IMAGES_RETURNS_OK.store(true, Ordering::SeqCst);
-        GET_USER_RETURNS_OK.store(true, Ordering::SeqCst);
-        DOM::local_storage()
-            .unwrap()
-            .set_item("user_id", "123456")
-            .unwrap();
-        VOTES_TO_DISPLAY.store(0, Ordering::SeqCst);
+    fn presentation_markdown_exists() {
+        // add 1 to len to run even if no languages are available
+        for language_index in 0..AVAILABLE_LANGUAGES.len() + 1 {
+            let language = Language {
+                index: language_index,
+            };
 
+            assert!(language
+                .load_file("presentation_side_by_side.md")
+                .is_some());
+            assert!(language.load_file("presentation_wipe.md").is_some());
+            assert!(language.load_file("presentation_flicker.md").is_some());
+            assert!(language.load_file("choose_image_button.md").is_some());
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn wipe_overlays_both_images() {
         render_yew_component!(ImagesToCompare);
         wasm_sleep_in_ms(150).await;
 
-        assert!(DOM::get_element_by_id("instructions_modal").is_some());
+        click_button("presentation_wipe_button");
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+
+        let wipe = DOM::get_element_by_id("wipe")
+            .expect("Element #wipe to be present");
+        assert_eq!(wipe.get_elements_by_tag_name("img").length(), 2);
+        assert!(DOM::get_element_by_id("zoom_controls").is_none());
     }
 
     #[wasm_bindgen_test]
-    async fn when_user_has_more_than_0_votes_do_not_show_instructions_modal() {
-        GET_IMAGES_RETURNS_OK.store(true, Ordering::SeqCst);
-        GET_USER_RETURNS_OK.store(true, Ordering::SeqCst);
-        DOM::local_storage()
-            .unwrap()
-            .set_item("user_id", "123456")
-            .unwrap();
-        VOTES_TO_DISPLAY.store(1, Ordering::SeqCst);
+    async fn image_is_chosen_by_label_in_wipe() {
+        let api = FakeClient::default();
 
-        render_yew_component!(ImagesToCompare);
+        render_yew_component!(ImagesToCompare, api.clone());
         wasm_sleep_in_ms(150).await;
 
-        assert!(DOM::get_element_by_id("instructions_modal").is_none());
+        click_button("presentation_wipe_button");
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+        let image = DOM::get_element_by_id("image_to_compare_1")
+            .and_then(|image| image.get_attribute("src"))
+            .expect("Second image to be present");
+        click_button("choose_image_button_1");
+        wasm_sleep_in_ms(50).await; // allow vote to be posted
+
+        let votes = api.posted_votes();
+        assert_eq!(votes.len(), 1);
+        assert_eq!(votes[0].vote_value(), &VoteValue::OneIsBetter(image));
+        assert_eq!(votes[0].recorded_presentation(), Presentation::Wipe);
+    }
+
+    fn shown_label() -> Option<String> {
+        DOM::get_element_by_id("flicker")?
+            .query_selector("[id^=image_label_]")
+            .ok()??
+            .text_content()
     }
 
     #[wasm_bindgen_test]
-    async fn button_to_show_instructions_modal_exists() {
+    async fn tapping_flicker_switches_image() {
         render_yew_component!(ImagesToCompare);
         wasm_sleep_in_ms(150).await;
 
-        assert!(
-            DOM::get_button_by_id("open_instructions_modal_button").is_some()
-        );
+        click_button("presentation_flicker_button");
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+        assert_eq!(shown_label().as_deref(), Some("A"));
+
+        DOM::get_element_by_id("flicker")
+            .expect("Element #flicker to be present")
+            .dyn_into::<web_sys::HtmlElement>()
+            .expect("Element to be castable to HtmlElement")
+            .click();
+        wasm_sleep_in_ms(50).await; // allow flicker to re-render
+
+        assert_eq!(shown_label().as_deref(), Some("B"));
     }
 
     #[wasm_bindgen_test]
-    fn instructions_button_sr_markdown_exists() {
-        // add 1 to len to run even if no languages are available
-        for language_index in 0..AVAILABLE_LANGUAGES.len() + 1 {
-            let language = Language {
-                index: language_index,
-            };
-            let file = language.load_file("instructions_button_sr.md");
+    async fn flicker_switches_image_at_interval() {
+        render_yew_component!(ImagesToCompare);
+        wasm_sleep_in_ms(150).await;
 
-            assert!(file.is_some())
-        }
+        click_button("presentation_flicker_button");
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+        assert_eq!(shown_label().as_deref(), Some("A"));
+
+        wasm_sleep_in_ms(550).await; // default interval is 500ms
+        assert_eq!(shown_label().as_deref(), Some("B"));
     }
 
     #[wasm_bindgen_test]
-    async fn instructions_button_sr_text_is_rendered() {
-        // add 1 to len to run even if no languages are available
-        for language_index in 0..AVAILABLE_LANGUAGES.len() + 1 {
-            DEFAULT_LANGUAGE.store(language_index, Ordering::SeqCst);
+    async fn vote_records_flicker_presentation() {
+        let api = FakeClient::default();
 
-            render_yew_component!(ImagesToCompare);
-            wasm_sleep_in_ms(50).await;
-
-            let language = Language::default();
-            let expected = language.load_file("instructions_button_sr.md");
-            let expected = markdown_to_decoded_html(expected.unwrap_or(""));
+        render_yew_component!(ImagesToCompare, api.clone());
+        wasm_sleep_in_ms(150).await;
 
-            let text = DOM::get_element_by_id("open_instructions_modal_button")
-                .expect("Element #open_instructions_modal_button to exist")
-                .last_element_child()
-                .expect("Last element child to exist");
+        click_button("presentation_flicker_button");
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+        click_button("choose_image_button_0");
+        wasm_sleep_in_ms(50).await; // allow vote to be posted
 
-            assert_eq!(text.inner_html(), expected);
-        }
+        let votes = api.posted_votes();
+        assert_eq!(votes.len(), 1);
+        assert_eq!(votes[0].recorded_presentation(), Presentation::Flicker);
     }
 
     #[wasm_bindgen_test]
-    async fn button_to_show_instructions_modal_works() {
-        GET_IMAGES_RETURNS_OK.store(true, Ordering::SeqCst);
-        GET_USER_RETURNS_OK.store(true, Ordering::SeqCst);
-        DOM::local_storage()
-            .unwrap()
-            .set_item("user_id", "123456")
-            .unwrap();
-        VOTES_TO_DISPLAY.store(1, Ordering::SeqCst);
+    async fn images_can_be_chosen_with_loupe_on() {
+        let api = FakeClient::default();
 
-        render_yew_component!(ImagesToCompare);
+        render_yew_component!(ImagesToCompare, api.clone());
         wasm_sleep_in_ms(150).await;
 
-        let button = DOM::get_button_by_id("open_instructions_modal_button")
-            .expect("Element #open_instructions_modal_button to be present")
-            .dyn_into::<web_sys::HtmlElement>()
-            .expect("Element to be castable to HtmlElement");
-
-        button.click();
+        click_button("loupe_button");
         wasm_sleep_in_ms(50).await; // allow page to re-render
-        assert!(DOM::get_element_by_id("instructions_modal").is_some());
+        click_button("image_to_compare_button_0");
+        wasm_sleep_in_ms(50).await; // allow vote to be posted
+
+        assert_eq!(api.posted_votes().len(), 1);
     }
 
     #[wasm_bindgen_test]
-    async fn instructions_modal_can_be_closed() {
-        GET_IMAGES_RETURNS_OK.store(true, Ordering::SeqCst);
-        GET_USER_RETURNS_OK.store(true, Ordering::SeqCst);
-        DOM::local_storage()
-            .unwrap()
-            .set_item("user_id", "123456")
-            .unwrap();
-        VOTES_TO_DISPLAY.store(0, Ordering::SeqCst);
+    fn reference_label_markdown_exists() {
+        // add 1 to len to run even if no languages are available
+        for language_index in 0..AVAILABLE_LANGUAGES.len() + 1 {
+            let language = Language {
+                index: language_index,
+            };
 
-        render_yew_component!(ImagesToCompare);
+            assert!(language.load_file("reference_label.md").is_some());
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn reference_is_shown_apart_from_images_to_compare() {
+        let api = FakeClient::default().comparisons(vec![Comparison {
+            id: "reference".to_string(),
+            images: vec!["/0.png".to_string(), "/1.png".to_string()],
+            reference: Some("/reference.png".to_string()),
+            catch_trial: None,
+        }]);
+
+        render_yew_component!(ImagesToCompare, api);
         wasm_sleep_in_ms(150).await;
 
-        let button = DOM::get_button_by_id("close_instructions_modal_button")
-            .expect("Element #close_instructions_modal_button to be present")
-            .dyn_into::<web_sys::HtmlElement>()
-            .expect("Element to be castable to HtmlElement");
+        let reference = DOM::get_element_by_id("reference_image")
+            .and_then(|image| image.get_attribute("src"))
+            .expect("Reference image to be present");
+        assert_eq!(reference, "/reference.png");
+        assert!(DOM::get_element_by_id("reference_label").is_some());
+        assert_eq!(
+            DOM::get_buttons_by_id_contains("image_to_compare_button_")
+                .unwrap_or_default()
+                .len(),
+            2
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn vote_records_the_size_the_images_were_shown_at() {
+        let api = FakeClient::default().comparisons(vec![Comparison {
+            id: "wide".to_string(),
+            images: vec![WIDE_IMAGE.to_string(), WIDE_IMAGE.to_string()],
+            reference: None,
+            catch_trial: None,
+        }]);
+
+        render_yew_component!(ImagesToCompare, api.clone());
+        wasm_sleep_in_ms(150).await;
+        click_button("image_to_compare_button_0");
+        wasm_sleep_in_ms(50).await; // allow vote to be posted
+
+        let votes = api.posted_votes();
+        let session = votes[0]
+            .recorded_session()
+            .expect("Vote to contain the session");
+        assert_eq!(session.stimuli.len(), 2);
+        for size in &session.stimuli {
+            let size = size.expect("Image to be measured");
+            // the wide image, not the square frame it is fitted in
+            assert!(size.width > 0.0);
+            assert!((size.width - 2.0 * size.height).abs() < 1e-6);
+        }
+    }
 
-        button.click();
+    #[wasm_bindgen_test]
+    async fn vote_records_the_size_of_the_images_unzoomed() {
+        let api = FakeClient::default().comparisons(vec![Comparison {
+            id: "wide".to_string(),
+            images: vec![WIDE_IMAGE.to_string(), WIDE_IMAGE.to_string()],
+            reference: None,
+            catch_trial: None,
+        }]);
+
+        render_yew_component!(ImagesToCompare, api.clone());
+        wasm_sleep_in_ms(150).await;
+        click_button("pixel_exact_button");
         wasm_sleep_in_ms(50).await; // allow page to re-render
-        assert!(DOM::get_element_by_id("instructions_modal").is_none());
+        let frame_width = DOM::get_element_by_id("image_to_compare_0")
+            .expect("Element #image_to_compare_0 to be present")
+            .client_width();
+        click_button("image_to_compare_button_0");
+        wasm_sleep_in_ms(50).await; // allow vote to be posted
+
+        let votes = api.posted_votes();
+        let viewport = votes[0]
+            .recorded_viewport()
+            .expect("Vote to carry the viewport");
+        assert!(viewport.pixel_exact);
+        let session = votes[0]
+            .recorded_session()
+            .expect("Vote to contain the session");
+        let size = session.stimuli[0].expect("Image to be measured");
+        // the zoom is recorded with the viewport, not in the size
+        assert_eq!(
+            size.width,
+            f64::from(frame_width) * DOM::device_pixel_ratio()
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn images_stay_zoomable_within_minimum_viewing_time() {
+        let api = FakeClient::default();
+
+        render_yew_component!(DwellingWhichIsBestPage, api.clone());
+        wasm_sleep_in_ms(100).await;
+
+        // the frames must get the wheel and pointer events
+        let button = DOM::get_button_by_id("image_to_compare_button_0")
+            .expect("Element #image_to_compare_button_0 to be present");
+        assert!(!button.has_attribute("disabled"));
+        click_button("image_to_compare_button_0");
+        wasm_sleep_in_ms(50).await; // allow vote to be posted
+        assert!(api.posted_votes().is_empty());
+
+        wasm_sleep_in_ms(200).await; // allow minimum viewing time to pass
+        click_button("image_to_compare_button_0");
+        wasm_sleep_in_ms(50).await; // allow vote to be posted
+        assert_eq!(api.posted_votes().len(), 1);
     }
 }
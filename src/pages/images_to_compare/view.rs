@@ -0,0 +1,344 @@
+use std::rc::Rc;
+
+use yew::Reducible;
+
+use crate::{
+    request::Viewport,
+    shared_components::loupe_style,
+};
+
+/// Highest magnification reachable by zooming.
+pub(super) const MAX_ZOOM: f64 = 32.0;
+
+/// Zoom and pan shared by all the images of a comparison.
+///
+/// Positions are normalized to the frame the images are fitted in, from
+/// 0 to 1 starting at its top-left corner.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) struct View {
+    zoom: f64,
+    /// Point of the images shown at the top-left corner of the frame.
+    left: f64,
+    top: f64,
+    pixel_exact: bool,
+}
+
+pub(super) enum ViewAction {
+    /// Multiplies the zoom by the factor, keeping the point under `(x, y)`
+    /// (in frame coordinates) in place.
+    Zoom {
+        factor: f64,
+        x: f64,
+        y: f64,
+    },
+    /// Moves the images by `(dx, dy)`, in frame coordinates.
+    Pan {
+        dx: f64,
+        dy: f64,
+    },
+    /// Zooms around the center so each image pixel takes one device pixel.
+    PixelExact {
+        zoom: f64,
+    },
+    Reset,
+}
+
+impl Default for View {
+    fn default() -> Self {
+        Self {
+            zoom: 1.0,
+            left: 0.0,
+            top: 0.0,
+            pixel_exact: false,
+        }
+    }
+}
+
+impl View {
+    pub(super) fn zoom(&self) -> f64 {
+        self.zoom
+    }
+
+    pub(super) fn is_pixel_exact(&self) -> bool {
+        self.pixel_exact
+    }
+
+    /// Keeps the frame covered by the images; when they are smaller than
+    /// the frame, they are centered instead.
+    fn clamped(self) -> Self {
+        let size = 1.0 / self.zoom;
+        let clamp = |start: f64| match size < 1.0 {
+            true => start.clamp(0.0, 1.0 - size),
+            false => (1.0 - size) / 2.0,
+        };
+
+        Self {
+            left: clamp(self.left),
+            top: clamp(self.top),
+            ..self
+        }
+    }
+
+    fn zoomed(self, zoom: f64, x: f64, y: f64, pixel_exact: bool) -> Self {
+        let (image_x, image_y) =
+            (self.left + x / self.zoom, self.top + y / self.zoom);
+
+        Self {
+            zoom,
+            left: image_x - x / zoom,
+            top: image_y - y / zoom,
+            pixel_exact,
+        }
+        .clamped()
+    }
+
+    /// CSS applied to each image.
+    pub(super) fn style(&self) -> String {
+        let mut style = format!(
+            "transform-origin: 0 0; transform: scale({}) translate({}%, {}%);",
+            self.zoom,
+            -self.left * 100.0,
+            -self.top * 100.0,
+        );
+        // show the actual pixels rather than a smoothed version of them
+        if self.zoom > 1.0 || self.pixel_exact {
+            style.push_str(" image-rendering: pixelated;");
+        }
+
+        style
+    }
+
+    /// CSS of the image in a loupe centered on `(x, y)` (in frame
+    /// coordinates), magnifying what is on screen by `magnification`.
+    pub(super) fn loupe_style(
+        &self,
+        x: f64,
+        y: f64,
+        magnification: f64,
+    ) -> String {
+        loupe_style(self.zoom, (self.left, self.top), (x, y), magnification)
+    }
+
+    pub(super) fn viewport(&self) -> Viewport {
+        let size = 1.0 / self.zoom;
+
+        Viewport {
+            zoom: self.zoom,
+            pixel_exact: self.pixel_exact,
+            x: self.left,
+            y: self.top,
+            width: size,
+            height: size,
+        }
+    }
+}
+
+impl Reducible for View {
+    type Action = ViewAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let view = *self;
+        match action {
+            ViewAction::Zoom { factor, x, y } => {
+                let zoom = (view.zoom * factor).clamp(1.0, MAX_ZOOM);
+                view.zoomed(zoom, x, y, false)
+            },
+            ViewAction::Pan { dx, dy } => Self {
+                left: view.left - dx / view.zoom,
+                top: view.top - dy / view.zoom,
+                ..view
+            }
+            .clamped(),
+            ViewAction::PixelExact { zoom } => {
+                view.zoomed(zoom, 0.5, 0.5, true)
+            },
+            ViewAction::Reset => View::default(),
+        }
+        .into()
+    }
+}
+
+/// Zoom at which an image of `natural` size (in image pixels), fitted in a
+/// frame of `frame` size (in CSS pixels), shows each image pixel on one
+/// device pixel.
+pub(super) fn pixel_exact_zoom(
+    natural: (f64, f64),
+    frame: (f64, f64),
+    device_pixel_ratio: f64,
+) -> Option<f64> {
+    let (natural_width, natural_height) = natural;
+    let (frame_width, frame_height) = frame;
+    if natural_width <= 0.0 || natural_height <= 0.0 || frame_width <= 0.0 {
+        return None;
+    }
+    // images are fitted with `object-contain`
+    let fitted_width =
+        frame_width.min(frame_height * natural_width / natural_height);
+
+    Some(natural_width / (fitted_width * device_pixel_ratio))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+    use yew::Reducible;
+
+    use super::{
+        pixel_exact_zoom,
+        View,
+        ViewAction,
+        MAX_ZOOM,
+    };
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn apply(view: View, action: ViewAction) -> View {
+        *Rc::new(view).reduce(action)
+    }
+
+    #[wasm_bindgen_test]
+    fn zooming_keeps_point_under_cursor_in_place() {
+        let view = apply(
+            View::default(),
+            ViewAction::Zoom {
+                factor: 2.0,
+                x: 1.0,
+                y: 1.0,
+            },
+        );
+
+        let viewport = view.viewport();
+        assert_eq!(viewport.zoom, 2.0);
+        assert_eq!((viewport.x, viewport.y), (0.5, 0.5));
+        assert_eq!((viewport.width, viewport.height), (0.5, 0.5));
+    }
+
+    #[wasm_bindgen_test]
+    fn zoom_is_limited() {
+        let zoomed_in = ViewAction::Zoom {
+            factor: 1000.0,
+            x: 0.5,
+            y: 0.5,
+        };
+        let zoomed_out = ViewAction::Zoom {
+            factor: 0.001,
+            x: 0.5,
+            y: 0.5,
+        };
+
+        assert_eq!(apply(View::default(), zoomed_in).zoom(), MAX_ZOOM);
+        assert_eq!(apply(View::default(), zoomed_out).zoom(), 1.0);
+    }
+
+    #[wasm_bindgen_test]
+    fn panning_stays_within_images() {
+        let view = apply(
+            View::default(),
+            ViewAction::Zoom {
+                factor: 2.0,
+                x: 0.5,
+                y: 0.5,
+            },
+        );
+        let view = apply(
+            view,
+            ViewAction::Pan {
+                dx: 10.0,
+                dy: -10.0,
+            },
+        );
+
+        let viewport = view.viewport();
+        assert_eq!((viewport.x, viewport.y), (0.0, 0.5));
+    }
+
+    #[wasm_bindgen_test]
+    fn images_smaller_than_frame_are_centered() {
+        let view = apply(View::default(), ViewAction::PixelExact { zoom: 0.5 });
+
+        let viewport = view.viewport();
+        assert!(viewport.pixel_exact);
+        assert_eq!((viewport.x, viewport.y), (-0.5, -0.5));
+        assert_eq!((viewport.width, viewport.height), (2.0, 2.0));
+    }
+
+    #[wasm_bindgen_test]
+    fn zooming_leaves_pixel_exact_mode() {
+        let view = apply(View::default(), ViewAction::PixelExact { zoom: 4.0 });
+        let view = apply(
+            view,
+            ViewAction::Zoom {
+                factor: 2.0,
+                x: 0.5,
+                y: 0.5,
+            },
+        );
+
+        assert!(!view.is_pixel_exact());
+    }
+
+    #[wasm_bindgen_test]
+    fn loupe_is_centered_on_point_under_pointer() {
+        // a 2x loupe is 1/3 of the frame, so the image in it is 6 loupes
+        // wide, and its center is 3 loupes from its left edge
+        let style = View::default().loupe_style(0.5, 0.5, 2.0);
+
+        assert!(style.contains("width: 600%;"));
+        assert!(style.contains("left: -250%;"));
+        assert!(style.contains("top: -250%;"));
+    }
+
+    #[wasm_bindgen_test]
+    fn loupe_magnifies_zoomed_images_further() {
+        let view = apply(
+            View::default(),
+            ViewAction::Zoom {
+                factor: 2.0,
+                x: 0.0,
+                y: 0.0,
+            },
+        );
+
+        let style = view.loupe_style(0.0, 0.0, 2.0);
+
+        assert!(style.contains("width: 1200%;"));
+        assert!(style.contains("left: 50%;"));
+    }
+
+    #[wasm_bindgen_test]
+    fn pixel_exact_zoom_accounts_for_device_pixel_ratio() {
+        // a 1000px image fitted in 500 CSS pixels is shown at 1:1 on a
+        // 2x display, and needs a 2x zoom on a 1x one
+        assert_eq!(
+            pixel_exact_zoom((1000.0, 1000.0), (500.0, 500.0), 2.0),
+            Some(1.0)
+        );
+        assert_eq!(
+            pixel_exact_zoom((1000.0, 1000.0), (500.0, 500.0), 1.0),
+            Some(2.0)
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn pixel_exact_zoom_accounts_for_letterboxing() {
+        // a wide image only fills the frame's width
+        assert_eq!(
+            pixel_exact_zoom((1000.0, 500.0), (500.0, 500.0), 1.0),
+            Some(2.0)
+        );
+        // a tall image only fills the frame's height
+        assert_eq!(
+            pixel_exact_zoom((500.0, 1000.0), (500.0, 500.0), 1.0),
+            Some(2.0)
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn pixel_exact_zoom_needs_loaded_image() {
+        assert_eq!(pixel_exact_zoom((0.0, 0.0), (500.0, 500.0), 1.0), None);
+    }
+}
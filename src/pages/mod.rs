@@ -1,5 +1,11 @@
+pub(crate) mod abx;
+pub(crate) mod artifacts;
+pub(crate) mod best_worst;
 pub(crate) mod equal_or_different;
+pub(crate) mod experiment;
 pub(crate) mod images_to_compare;
+pub(crate) mod ranking;
+pub(crate) mod rating;
 
 use yew::{
     AttrValue,
@@ -7,8 +13,13 @@ use yew::{
 };
 
 pub(crate) use self::{
-    equal_or_different::ImagesToCompare as ExperimentEqualOrDifferent,
+    abx::ExperimentAbx,
+    artifacts::ExperimentArtifacts,
+    best_worst::ExperimentBestWorst,
+    equal_or_different::ExperimentEqualOrDifferent,
     images_to_compare::ImagesToCompare,
+    ranking::ExperimentRanking,
+    rating::ExperimentRating,
 };
 
 pub(crate) fn markdown_to_yew_html(text: &str) -> Html {
@@ -0,0 +1,376 @@
+use yew::{
+    classes,
+    function_component,
+    html,
+    use_context,
+    use_effect_with,
+    use_state_eq,
+    Callback,
+    Html,
+    UseReducerHandle,
+};
+
+use crate::{
+    pages::{
+        experiment::{
+            Experiment,
+            ExperimentPage,
+            ExposureMask,
+            StimuliProps,
+        },
+        markdown_to_yew_html,
+    },
+    request::VoteValue,
+    shared_components::{
+        Button,
+        ImageGrid,
+    },
+    Language,
+};
+
+/// Full ranking: the participant orders every image from best to worst by
+/// clicking them in turn.
+pub(crate) struct Ranking;
+
+impl Experiment for Ranking {
+    type Stimuli = RankingStimuli;
+
+    const PROMPT: &'static str = "rank_images_prompt";
+}
+
+pub(crate) type ExperimentRanking = ExperimentPage<Ranking>;
+
+#[function_component(RankingStimuli)]
+pub(crate) fn ranking_stimuli(props: &StimuliProps) -> Html {
+    let language = match use_context::<UseReducerHandle<Language>>() {
+        Some(ctx) => (*ctx).clone(),
+        None => Language::default(),
+    };
+    // indexes into `props.images`, best first
+    let ranking = use_state_eq(Vec::<usize>::new);
+
+    {
+        let ranking = ranking.clone();
+        use_effect_with((props.loading, props.images.clone()), move |_| {
+            ranking.set(Vec::new())
+        });
+    }
+
+    let submit_ranking_button = language.load_file("submit_ranking_button.md");
+    let submit_ranking_button =
+        markdown_to_yew_html(submit_ranking_button.unwrap_or(""));
+
+    let reset_ranking_button = language.load_file("reset_ranking_button.md");
+    let reset_ranking_button =
+        markdown_to_yew_html(reset_ranking_button.unwrap_or(""));
+
+    let toggle_rank = {
+        let ranking = ranking.clone();
+        let answer_disabled = props.answer_disabled();
+        Callback::from(move |index: usize| {
+            if answer_disabled {
+                return;
+            }
+            let mut new_ranking = (*ranking).clone();
+            match new_ranking.iter().position(|x| *x == index) {
+                Some(position) => {
+                    new_ranking.remove(position);
+                },
+                None => new_ranking.push(index),
+            }
+            ranking.set(new_ranking);
+        })
+    };
+
+    let reset_ranking = {
+        let ranking = ranking.clone();
+        Callback::from(move |_| ranking.set(Vec::new()))
+    };
+
+    let submit_ranking = {
+        let ranking = ranking.clone();
+        let images = props.images.clone();
+        let onvote = props.onvote.clone();
+        Callback::from(move |_| {
+            let ordered_images =
+                ranking.iter().map(|index| images[*index].clone()).collect();
+            onvote.emit(VoteValue::Ranking(ordered_images));
+        })
+    };
+
+    let ranking_complete =
+        !props.images.is_empty() && ranking.len() == props.images.len();
+
+    let rank_badges = (0..props.images.len())
+        .map(|index| {
+            let Some(rank) = ranking.iter().position(|x| *x == index) else {
+                return html! {};
+            };
+
+            html! {
+                <span
+                    id={format!("rank_of_image_{index}")}
+                    class={classes![
+                        "absolute",
+                        "top-4",
+                        "left-4",
+                        "w-10",
+                        "h-10",
+                        "rounded-full",
+                        "bg-gray-800",
+                        "text-gray-100",
+                        "flex",
+                        "items-center",
+                        "justify-center",
+                    ]}
+                >
+                    { rank + 1 }
+                </span>
+            }
+        })
+        .collect::<Vec<_>>();
+
+    html! {
+        <>
+            <ImageGrid
+                loading={props.loading}
+                images={props.images.clone()}
+                reference={props.reference.clone()}
+                onclick={toggle_rank}
+                badges={rank_badges}
+            >
+                <ExposureMask phase={props.exposure} />
+            </ImageGrid>
+            <section
+                id="ranking_buttons"
+                class={classes![
+                    "self-center",
+                    "flex",
+                    "flex-row",
+                    "w-1/2",
+                    "items-stretch",
+                    "justify-center",
+                    "gap-1",
+                    "md:gap-4",
+                    "my-4",
+                    "md:mt-0",
+                ]}
+            >
+                <Button
+                    id="reset_ranking"
+                    class={classes![
+                        "border-2",
+                        "border-gray-800",
+                        "hover:text-gray-200",
+                        "w-full",
+                    ]}
+                    disabled={props.loading}
+                    onclick={reset_ranking}
+                >
+                    { reset_ranking_button }
+                </Button>
+                <Button
+                    id="submit_ranking"
+                    class={classes![
+                        "border-2",
+                        "border-gray-800",
+                        "hover:text-gray-200",
+                        "w-full",
+                    ]}
+                    disabled={props.answer_disabled() || !ranking_complete}
+                    onclick={submit_ranking}
+                >
+                    { submit_ranking_button }
+                </Button>
+            </section>
+        </>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+
+    use super::{
+        ExperimentRanking,
+        RankingStimuli,
+    };
+    use crate::{
+        click_button,
+        dom::DOM,
+        pages::experiment::{
+            Experiment,
+            ExperimentPage,
+        },
+        render_yew_component,
+        request::{
+            Comparison,
+            FakeClient,
+        },
+        wasm_sleep_in_ms,
+        Language,
+        AVAILABLE_LANGUAGES,
+    };
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    struct DwellingRanking;
+
+    impl Experiment for DwellingRanking {
+        type Stimuli = RankingStimuli;
+
+        const MIN_VIEWING_TIME: Option<Duration> =
+            Some(Duration::from_millis(200));
+        const PROMPT: &'static str = "rank_images_prompt";
+    }
+
+    type DwellingRankingPage = ExperimentPage<DwellingRanking>;
+
+    fn submit_ranking_is_disabled() -> bool {
+        DOM::get_button_by_id("submit_ranking")
+            .expect("Element #submit_ranking to be present")
+            .has_attribute("disabled")
+    }
+
+    #[wasm_bindgen_test]
+    fn ranking_markdown_exists() {
+        // add 1 to len to run even if no languages are available
+        for language_index in 0..AVAILABLE_LANGUAGES.len() + 1 {
+            let language = Language {
+                index: language_index,
+            };
+
+            assert!(language.load_file("rank_images_prompt.md").is_some());
+            assert!(language.load_file("submit_ranking_button.md").is_some());
+            assert!(language.load_file("reset_ranking_button.md").is_some());
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn clicking_images_shows_their_rank() {
+        render_yew_component!(ExperimentRanking);
+        wasm_sleep_in_ms(150).await;
+
+        click_button("image_to_compare_button_1");
+        click_button("image_to_compare_button_0");
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+
+        let rank_of = |index: usize| {
+            DOM::get_element_by_id(&format!("rank_of_image_{index}"))
+                .expect("Rank badge to be present")
+                .inner_html()
+        };
+        assert_eq!(rank_of(1), "1");
+        assert_eq!(rank_of(0), "2");
+    }
+
+    #[wasm_bindgen_test]
+    async fn clicking_a_ranked_image_unranks_it() {
+        render_yew_component!(ExperimentRanking);
+        wasm_sleep_in_ms(150).await;
+
+        click_button("image_to_compare_button_0");
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+        click_button("image_to_compare_button_0");
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+
+        assert!(DOM::get_element_by_id("rank_of_image_0").is_none());
+    }
+
+    #[wasm_bindgen_test]
+    async fn submit_is_disabled_until_all_images_are_ranked() {
+        render_yew_component!(ExperimentRanking);
+        wasm_sleep_in_ms(150).await;
+
+        assert!(submit_ranking_is_disabled());
+
+        click_button("image_to_compare_button_0");
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+        assert!(submit_ranking_is_disabled());
+
+        click_button("image_to_compare_button_1");
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+        assert!(!submit_ranking_is_disabled());
+    }
+
+    #[wasm_bindgen_test]
+    async fn reset_button_clears_ranking() {
+        render_yew_component!(ExperimentRanking);
+        wasm_sleep_in_ms(150).await;
+
+        click_button("image_to_compare_button_0");
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+        click_button("reset_ranking");
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+
+        assert!(DOM::get_element_by_id("rank_of_image_0").is_none());
+    }
+
+    #[wasm_bindgen_test]
+    async fn submitting_ranking_loads_new_images() {
+        render_yew_component!(ExperimentRanking);
+        wasm_sleep_in_ms(150).await;
+
+        click_button("image_to_compare_button_0");
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+        click_button("image_to_compare_button_1");
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+        click_button("submit_ranking");
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+
+        assert!(DOM::get_images_by_id_contains("image_to_compare_").is_none());
+        wasm_sleep_in_ms(100).await; // allow images to actually load
+        assert_eq!(
+            DOM::get_images_by_id_contains("image_to_compare_")
+                .unwrap_or_default()
+                .len(),
+            2
+        );
+        assert!(DOM::get_element_by_id("rank_of_image_0").is_none());
+    }
+
+    #[wasm_bindgen_test]
+    async fn images_are_not_ranked_within_minimum_viewing_time() {
+        render_yew_component!(DwellingRankingPage);
+        wasm_sleep_in_ms(100).await;
+
+        click_button("image_to_compare_button_0");
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+        assert!(DOM::get_element_by_id("rank_of_image_0").is_none());
+
+        wasm_sleep_in_ms(200).await; // allow minimum viewing time to pass
+        click_button("image_to_compare_button_0");
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+        assert!(DOM::get_element_by_id("rank_of_image_0").is_some());
+    }
+
+    #[wasm_bindgen_test]
+    async fn reference_is_shown_apart_from_the_images() {
+        let api = FakeClient::default().comparisons(vec![Comparison {
+            id: "ranking".to_string(),
+            images: (0..3).map(|index| format!("/{index}.png")).collect(),
+            reference: Some("/reference.png".to_string()),
+            catch_trial: None,
+        }]);
+
+        render_yew_component!(ExperimentRanking, api);
+        wasm_sleep_in_ms(150).await;
+
+        let reference = DOM::get_element_by_id("reference_image")
+            .and_then(|image| image.get_attribute("src"))
+            .expect("Reference image to be present");
+        assert_eq!(reference, "/reference.png");
+        assert!(DOM::get_element_by_id("reference_label").is_some());
+        assert_eq!(
+            DOM::get_images_by_id_contains("image_to_compare_")
+                .unwrap_or_default()
+                .len(),
+            3
+        );
+    }
+}
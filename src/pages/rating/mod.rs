@@ -0,0 +1,347 @@
+use yew::{
+    classes,
+    function_component,
+    html,
+    use_context,
+    Html,
+    UseReducerHandle,
+};
+
+use crate::{
+    pages::{
+        experiment::{
+            Experiment,
+            ExperimentPage,
+            ExposureMask,
+            StimuliProps,
+        },
+        markdown_to_yew_html,
+    },
+    request::VoteValue,
+    shared_components::{
+        Button,
+        Loading,
+        ReferenceImage,
+    },
+    Language,
+};
+
+/// Absolute category rating: the participant scores a single image.
+pub(crate) struct Rating;
+
+impl Experiment for Rating {
+    type Stimuli = RatingStimuli;
+
+    const PROMPT: &'static str = "rate_image_prompt";
+}
+
+pub(crate) type ExperimentRating = ExperimentPage<Rating>;
+
+#[function_component(RatingStimuli)]
+pub(crate) fn rating_stimuli(props: &StimuliProps) -> Html {
+    let language = match use_context::<UseReducerHandle<Language>>() {
+        Some(ctx) => (*ctx).clone(),
+        None => Language::default(),
+    };
+
+    let image = props.images.first().cloned().unwrap_or_default();
+    let reference = props.reference.clone().filter(|_| !props.loading);
+    // side by side with the reference on larger screens, stacked otherwise
+    let image_height = match reference {
+        Some(_) => classes!["h-1/2", "md:h-5/6"],
+        None => classes!["h-5/6"],
+    };
+
+    let rating_buttons = props
+        .rating_scale
+        .clone()
+        .map(|score| {
+            let label = language
+                .load_file(&format!("rating_scale_{score}.md"))
+                .map(markdown_to_yew_html)
+                .unwrap_or_else(|| html! { { score } });
+            let onclick = {
+                let onvote = props.onvote.clone();
+                move |_| onvote.emit(VoteValue::Rating(score))
+            };
+
+            html! {
+                <Button
+                    id={format!("rating_button_{score}")}
+                    class={classes![
+                        "border-2",
+                        "border-gray-800",
+                        "hover:text-gray-200",
+                        "w-full",
+                    ]}
+                    disabled={props.answer_disabled()}
+                    onclick={onclick}
+                >
+                    { label }
+                </Button>
+            }
+        })
+        .collect::<Html>();
+
+    html! {
+        <>
+            <section
+                id="images_list"
+                class={classes![
+                    "relative",
+                    "flex-1",
+                    "overflow-hidden",
+                    "flex",
+                    "flex-col",
+                    "md:flex-row",
+                    "items-center",
+                    "justify-center",
+                    "md:justify-evenly",
+                    "gap-4",
+                ]}
+            >
+                if let Some(reference) = reference {
+                    <ReferenceImage image={reference} />
+                }
+                <div
+                    class={classes![
+                        image_height,
+                        "aspect-square",
+                        "w-fit",
+                    ]}
+                >
+                    if props.loading {
+                        <div id="loading_status_button_0">
+                            <Loading />
+                        </div>
+                    } else {
+                        <img
+                            id="image_to_compare_0"
+                            class={classes!["h-full"]}
+                            src={image}
+                            alt=""
+                        />
+                    }
+                </div>
+                <ExposureMask phase={props.exposure} />
+            </section>
+            <section
+                id="rating_buttons"
+                class={classes![
+                    "self-center",
+                    "flex",
+                    "flex-col",
+                    "md:flex-row",
+                    "w-1/2",
+                    "items-stretch",
+                    "md:justify-center",
+                    "gap-1",
+                    "md:gap-4",
+                    "my-4",
+                    "md:mt-0",
+                ]}
+            >
+                { rating_buttons }
+            </section>
+        </>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        ops::RangeInclusive,
+        sync::atomic::Ordering,
+    };
+
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+
+    use super::{
+        ExperimentRating,
+        Rating,
+        RatingStimuli,
+    };
+    use crate::{
+        dom::DOM,
+        markdown_to_decoded_html,
+        pages::experiment::{
+            Experiment,
+            ExperimentPage,
+        },
+        render_yew_component,
+        request::{
+            Comparison,
+            FakeClient,
+            VoteValue,
+        },
+        wasm_sleep_in_ms,
+        Language,
+        AVAILABLE_LANGUAGES,
+        DEFAULT_LANGUAGE,
+    };
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    struct SevenPointRating;
+
+    impl Experiment for SevenPointRating {
+        type Stimuli = RatingStimuli;
+
+        const PROMPT: &'static str = "rate_image_prompt";
+        const RATING_SCALE: RangeInclusive<u8> = 1..=7;
+    }
+
+    type SevenPointRatingPage = ExperimentPage<SevenPointRating>;
+
+    #[wasm_bindgen_test]
+    fn rate_image_prompt_markdown_exists() {
+        // add 1 to len to run even if no languages are available
+        for language_index in 0..AVAILABLE_LANGUAGES.len() + 1 {
+            let language = Language {
+                index: language_index,
+            };
+            let file = language.load_file("rate_image_prompt.md");
+
+            assert!(file.is_some())
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn rating_scale_markdown_exists() {
+        // add 1 to len to run even if no languages are available
+        for language_index in 0..AVAILABLE_LANGUAGES.len() + 1 {
+            let language = Language {
+                index: language_index,
+            };
+            for score in Rating::RATING_SCALE {
+                let file =
+                    language.load_file(&format!("rating_scale_{score}.md"));
+
+                assert!(file.is_some())
+            }
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn one_image_to_rate_exists() {
+        render_yew_component!(ExperimentRating);
+        wasm_sleep_in_ms(150).await;
+
+        assert_eq!(
+            DOM::get_images_by_id_contains("image_to_compare_")
+                .unwrap_or_default()
+                .len(),
+            1
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn rating_buttons_show_scale_labels() {
+        // add 1 to len to run even if no languages are available
+        for language_index in 0..AVAILABLE_LANGUAGES.len() + 1 {
+            DEFAULT_LANGUAGE.store(language_index, Ordering::SeqCst);
+
+            render_yew_component!(ExperimentRating);
+            wasm_sleep_in_ms(50).await;
+
+            let language = Language::default();
+            for score in Rating::RATING_SCALE {
+                let expected =
+                    language.load_file(&format!("rating_scale_{score}.md"));
+                let expected = markdown_to_decoded_html(expected.unwrap_or(""));
+
+                let button =
+                    DOM::get_button_by_id(&format!("rating_button_{score}"))
+                        .expect("Rating button to exist");
+
+                assert_eq!(button.inner_html(), expected);
+            }
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn rating_buttons_follow_the_experiment_scale() {
+        render_yew_component!(SevenPointRatingPage);
+        wasm_sleep_in_ms(150).await;
+
+        for score in SevenPointRating::RATING_SCALE {
+            assert!(DOM::get_button_by_id(&format!("rating_button_{score}"))
+                .is_some());
+        }
+        assert!(DOM::get_button_by_id("rating_button_8").is_none());
+        // points without a label of their own show their number
+        let button = DOM::get_button_by_id("rating_button_7")
+            .expect("Rating button to exist");
+        assert_eq!(button.inner_html(), "7");
+    }
+
+    #[wasm_bindgen_test]
+    async fn choosing_a_rating_loads_new_image() {
+        render_yew_component!(ExperimentRating);
+        wasm_sleep_in_ms(150).await;
+
+        let button = DOM::get_button_by_id("rating_button_3")
+            .expect("Button to be present")
+            .dyn_into::<web_sys::HtmlElement>()
+            .expect("Element to be castable to HtmlElement");
+
+        button.click();
+        wasm_sleep_in_ms(50).await; // allow page to re-render
+        assert!(DOM::get_images_by_id_contains("image_to_compare_").is_none());
+        wasm_sleep_in_ms(100).await; // allow images to actually load
+        assert_eq!(
+            DOM::get_images_by_id_contains("image_to_compare_")
+                .unwrap_or_default()
+                .len(),
+            1
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn choosing_a_rating_posts_it() {
+        let api = FakeClient::default();
+
+        render_yew_component!(ExperimentRating, api.clone());
+        wasm_sleep_in_ms(150).await;
+
+        DOM::get_button_by_id("rating_button_4")
+            .expect("Button to be present")
+            .dyn_into::<web_sys::HtmlElement>()
+            .expect("Element to be castable to HtmlElement")
+            .click();
+        wasm_sleep_in_ms(50).await; // allow vote to be posted
+
+        let votes = api.posted_votes();
+        assert_eq!(votes.len(), 1);
+        assert_eq!(votes[0].vote_value(), &VoteValue::Rating(4));
+    }
+
+    #[wasm_bindgen_test]
+    async fn reference_is_shown_next_to_the_rated_image() {
+        let api = FakeClient::default().comparisons(vec![Comparison {
+            id: "reference".to_string(),
+            images: vec!["/0.png".to_string()],
+            reference: Some("/reference.png".to_string()),
+            catch_trial: None,
+        }]);
+
+        render_yew_component!(ExperimentRating, api);
+        wasm_sleep_in_ms(150).await;
+
+        let reference = DOM::get_element_by_id("reference_image")
+            .and_then(|image| image.get_attribute("src"))
+            .expect("Reference image to be present");
+        assert_eq!(reference, "/reference.png");
+        assert!(DOM::get_element_by_id("reference_label").is_some());
+        assert_eq!(
+            DOM::get_images_by_id_contains("image_to_compare_")
+                .unwrap_or_default()
+                .len(),
+            1
+        );
+    }
+}
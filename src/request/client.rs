@@ -0,0 +1,131 @@
+use std::{
+    ops::Deref,
+    rc::Rc,
+};
+
+use futures::future::LocalBoxFuture;
+use yew::{
+    hook,
+    use_context,
+};
+
+use super::{
+    config::ApiConfig,
+    images,
+    user,
+    Comparison,
+    RequestError,
+    User,
+    Vote,
+};
+
+pub(crate) type ApiFuture<T> = LocalBoxFuture<'static, Result<T, RequestError>>;
+
+/// The requests the app makes to the API.
+///
+/// Components get the client through the [`Api`] context (see [`use_api`]),
+/// so tests can provide a fake one instead of [`GlooClient`].
+pub(crate) trait ApiClient {
+    fn get_user_by_id(&self, id: String) -> ApiFuture<User>;
+
+    fn generate_user(&self) -> ApiFuture<User>;
+
+    /// Fetches the next comparison for the user and preloads its images,
+    /// never one of the comparisons in `exclude`.
+    fn get_comparison_for_user(
+        &self,
+        user_id: String,
+        dirname: String,
+        exclude: Vec<String>,
+    ) -> ApiFuture<Comparison>;
+
+    /// Whether the API still accepts votes on the comparison.
+    fn comparison_is_valid(&self, comparison_id: String) -> ApiFuture<bool>;
+
+    fn post_vote(&self, vote: Vote) -> ApiFuture<()>;
+}
+
+/// Sends the requests to the API with `gloo_net`.
+#[derive(Default)]
+pub(crate) struct GlooClient {
+    config: Rc<ApiConfig>,
+}
+
+impl GlooClient {
+    pub(crate) fn new(config: ApiConfig) -> Self {
+        Self {
+            config: Rc::new(config),
+        }
+    }
+}
+
+impl ApiClient for GlooClient {
+    fn get_user_by_id(&self, id: String) -> ApiFuture<User> {
+        Box::pin(user::get_user_by_id(self.config.clone(), id))
+    }
+
+    fn generate_user(&self) -> ApiFuture<User> {
+        Box::pin(user::generate_user(self.config.clone()))
+    }
+
+    fn get_comparison_for_user(
+        &self,
+        user_id: String,
+        dirname: String,
+        exclude: Vec<String>,
+    ) -> ApiFuture<Comparison> {
+        Box::pin(images::get_comparison_for_user(
+            self.config.clone(),
+            user_id,
+            dirname,
+            exclude,
+        ))
+    }
+
+    fn comparison_is_valid(&self, comparison_id: String) -> ApiFuture<bool> {
+        Box::pin(images::comparison_is_valid(
+            self.config.clone(),
+            comparison_id,
+        ))
+    }
+
+    fn post_vote(&self, vote: Vote) -> ApiFuture<()> {
+        Box::pin(images::post_vote(self.config.clone(), vote))
+    }
+}
+
+/// Shared handle to the [`ApiClient`], provided as a Yew context.
+#[derive(Clone)]
+pub(crate) struct Api(Rc<dyn ApiClient>);
+
+impl<C: ApiClient + 'static> From<C> for Api {
+    fn from(client: C) -> Self {
+        Self(Rc::new(client))
+    }
+}
+
+impl Default for Api {
+    fn default() -> Self {
+        GlooClient::default().into()
+    }
+}
+
+impl PartialEq for Api {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Deref for Api {
+    type Target = dyn ApiClient;
+
+    fn deref(&self) -> &Self::Target {
+        self.0.as_ref()
+    }
+}
+
+/// The [`Api`] from context, falling back to a [`GlooClient`].
+#[hook]
+pub(crate) fn use_api() -> Api {
+    use_context::<Api>().unwrap_or_default()
+}
@@ -0,0 +1,176 @@
+use serde::Deserialize;
+
+use crate::dom::{
+    console_error,
+    DOM,
+};
+
+/// Where the API and the images are hosted, when not on the same origin as
+/// the frontend.
+///
+/// Read at startup from `/config.json`, falling back (field by field) to
+/// the `api-base-url` and `image-origin` `<meta>` tags of `index.html`.
+/// Empty values mean the same origin as the frontend.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct ApiConfig {
+    pub(crate) api_base_url: String,
+    pub(crate) image_origin: String,
+}
+
+#[derive(Deserialize)]
+struct ConfigFile {
+    api_base_url: Option<String>,
+    image_origin: Option<String>,
+}
+
+impl ApiConfig {
+    fn from_meta_tags() -> Self {
+        Self {
+            api_base_url: DOM::meta_content("api-base-url").unwrap_or_default(),
+            image_origin: DOM::meta_content("image-origin").unwrap_or_default(),
+        }
+    }
+
+    fn with_file(self, file: ConfigFile) -> Self {
+        Self {
+            api_base_url: file
+                .api_base_url
+                .filter(|url| !url.is_empty())
+                .unwrap_or(self.api_base_url),
+            image_origin: file
+                .image_origin
+                .filter(|origin| !origin.is_empty())
+                .unwrap_or(self.image_origin),
+        }
+    }
+
+    /// Loads the configuration; a missing or invalid `/config.json` leaves
+    /// the `<meta>` tags in charge.
+    pub(crate) async fn load() -> Self {
+        let config = Self::from_meta_tags();
+
+        let response =
+            match gloo_net::http::Request::get("/config.json").send().await {
+                Ok(response) if response.ok() => response,
+                _ => return config,
+            };
+        match response.json::<ConfigFile>().await {
+            Ok(file) => config.with_file(file),
+            Err(error) => {
+                console_error!(format!("invalid config.json: {error}"));
+                config
+            },
+        }
+    }
+
+    /// URL of an API endpoint, `path` starting with `/api/`.
+    pub(crate) fn api_url(&self, path: &str) -> String {
+        format!("{}{path}", self.api_base_url.trim_end_matches('/'))
+    }
+
+    /// URL of an image given by the API. Images the API already gives as
+    /// full URLs are left untouched.
+    pub(crate) fn image_url(&self, image: &str) -> String {
+        if image.contains("://") || !image.starts_with('/') {
+            return image.to_string();
+        }
+        format!("{}{image}", self.image_origin.trim_end_matches('/'))
+    }
+
+    /// The image as given by the API, undoing [`ApiConfig::image_url`].
+    pub(crate) fn image_path(&self, url: &str) -> String {
+        let origin = self.image_origin.trim_end_matches('/');
+        match url.strip_prefix(origin) {
+            Some(path) if !origin.is_empty() && path.starts_with('/') => {
+                path.to_string()
+            },
+            _ => url.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+
+    use super::{
+        ApiConfig,
+        ConfigFile,
+    };
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn cdn_config() -> ApiConfig {
+        ApiConfig {
+            api_base_url: "https://api.example.com/".to_string(),
+            image_origin: "https://images.example.com".to_string(),
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn default_config_keeps_relative_paths() {
+        let config = ApiConfig::default();
+
+        assert_eq!(config.api_url("/api/vote"), "/api/vote");
+        assert_eq!(
+            config.image_url("/static/images/0.png"),
+            "/static/images/0.png"
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn api_url_is_prefixed_with_base_url() {
+        assert_eq!(
+            cdn_config().api_url("/api/vote"),
+            "https://api.example.com/api/vote"
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn image_url_is_prefixed_with_image_origin() {
+        assert_eq!(
+            cdn_config().image_url("/static/images/0.png"),
+            "https://images.example.com/static/images/0.png"
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn full_image_urls_are_left_untouched() {
+        let image = "https://other.example.com/0.png";
+
+        assert_eq!(cdn_config().image_url(image), image);
+    }
+
+    #[wasm_bindgen_test]
+    fn image_path_undoes_image_url() {
+        let config = cdn_config();
+        let image = "/static/images/0.png";
+
+        assert_eq!(config.image_path(&config.image_url(image)), image);
+    }
+
+    #[wasm_bindgen_test]
+    fn config_file_overrides_only_given_fields() {
+        let file = ConfigFile {
+            api_base_url: Some("https://api.example.org".to_string()),
+            image_origin: None,
+        };
+
+        let config = cdn_config().with_file(file);
+
+        assert_eq!(config.api_base_url, "https://api.example.org");
+        assert_eq!(config.image_origin, "https://images.example.com");
+    }
+
+    #[wasm_bindgen_test]
+    fn empty_config_file_fields_keep_meta_tags() {
+        let file = ConfigFile {
+            api_base_url: Some(String::new()),
+            image_origin: Some(String::new()),
+        };
+
+        assert_eq!(cdn_config().with_file(file), cdn_config());
+    }
+}
@@ -0,0 +1,145 @@
+use std::fmt;
+
+/// Why a request to the API (or to the browser's storage on its behalf)
+/// failed.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum RequestError {
+    /// The request never got a response, e.g. the participant is offline.
+    Network(String),
+    /// The API answered with a non-2xx status, with the `error` string of
+    /// its envelope when there was one.
+    Status { code: u16, error: Option<String> },
+    /// The body could not be (de)serialized as JSON.
+    Json(String),
+    /// The API answered successfully but reported an `error`, with the
+    /// status `code` of its envelope when there was one.
+    Server { code: Option<u16>, error: String },
+    /// The envelope had neither or both of `data` and `error`.
+    InvalidEnvelope(String),
+    /// `localStorage` is unavailable or refused the operation.
+    Storage(String),
+}
+
+impl RequestError {
+    /// Whether sending the same request again may succeed.
+    pub(crate) fn is_retryable(&self) -> bool {
+        let is_transient =
+            |code: u16| code >= 500 || code == 408 || code == 429;
+        match self {
+            RequestError::Network(_) => true,
+            RequestError::Status { code, .. } => is_transient(*code),
+            RequestError::Server {
+                code: Some(code), ..
+            } => is_transient(*code),
+            _ => false,
+        }
+    }
+
+    /// Markdown file explaining the error to the participant.
+    pub(crate) fn message_file(&self) -> &'static str {
+        match self {
+            RequestError::Network(_) => "network_error_message.md",
+            RequestError::Storage(_) => "storage_error_message.md",
+            _ => "fatal_error_message.md",
+        }
+    }
+}
+
+impl fmt::Display for RequestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RequestError::Network(error) => write!(f, "network error: {error}"),
+            RequestError::Status { code, error } => match error {
+                Some(error) => write!(f, "HTTP status {code}: {error}"),
+                None => write!(f, "HTTP status {code}"),
+            },
+            RequestError::Json(error) => write!(f, "invalid JSON: {error}"),
+            RequestError::Server { code, error } => match code {
+                Some(code) => write!(f, "server error {code}: {error}"),
+                None => write!(f, "server error: {error}"),
+            },
+            RequestError::InvalidEnvelope(error) => {
+                write!(f, "invalid response envelope: {error}")
+            },
+            RequestError::Storage(error) => {
+                write!(f, "local storage error: {error}")
+            },
+        }
+    }
+}
+
+impl From<gloo_net::Error> for RequestError {
+    fn from(error: gloo_net::Error) -> Self {
+        match error {
+            gloo_net::Error::SerdeError(error) => {
+                RequestError::Json(error.to_string())
+            },
+            error => RequestError::Network(error.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+
+    use super::RequestError;
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    fn network_errors_are_retryable() {
+        let error = RequestError::Network("offline".to_string());
+
+        assert!(error.is_retryable());
+    }
+
+    #[wasm_bindgen_test]
+    fn server_side_statuses_are_retryable() {
+        let error = RequestError::Status {
+            code: 503,
+            error: None,
+        };
+
+        assert!(error.is_retryable());
+    }
+
+    #[wasm_bindgen_test]
+    fn client_side_statuses_are_not_retryable() {
+        let error = RequestError::Status {
+            code: 404,
+            error: Some("User not found".to_string()),
+        };
+
+        assert!(!error.is_retryable());
+    }
+
+    #[wasm_bindgen_test]
+    fn server_errors_are_not_retryable() {
+        let error = RequestError::Server {
+            code: None,
+            error: "Invalid vote".to_string(),
+        };
+
+        assert!(!error.is_retryable());
+    }
+
+    #[wasm_bindgen_test]
+    fn server_errors_with_transient_code_are_retryable() {
+        let error = RequestError::Server {
+            code: Some(503),
+            error: "Database unavailable".to_string(),
+        };
+
+        assert!(error.is_retryable());
+    }
+
+    #[wasm_bindgen_test]
+    fn invalid_envelopes_are_not_retryable() {
+        let error = RequestError::InvalidEnvelope("empty".to_string());
+
+        assert!(!error.is_retryable());
+    }
+}
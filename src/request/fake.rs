@@ -0,0 +1,226 @@
+use std::{
+    cell::RefCell,
+    collections::{
+        HashSet,
+        VecDeque,
+    },
+    rc::Rc,
+    time::Duration,
+};
+
+use super::{
+    client::{
+        ApiClient,
+        ApiFuture,
+    },
+    Comparison,
+    RequestError,
+    User,
+    Vote,
+    VoteValue,
+};
+
+struct FakeState {
+    user_votes: usize,
+    user_error: Option<RequestError>,
+    comparisons: VecDeque<Comparison>,
+    comparison_error: Option<RequestError>,
+    comparison_latency: Duration,
+    invalidated: HashSet<String>,
+    validity_error: Option<RequestError>,
+    vote_error: Option<RequestError>,
+    vote_latency: Duration,
+    posted_votes: Vec<Vote>,
+}
+
+impl Default for FakeState {
+    fn default() -> Self {
+        Self {
+            user_votes: 0,
+            user_error: None,
+            comparisons: VecDeque::new(),
+            comparison_error: None,
+            // long enough for tests to see the loading status
+            comparison_latency: Duration::from_millis(50),
+            invalidated: HashSet::new(),
+            validity_error: None,
+            vote_error: None,
+            vote_latency: Duration::ZERO,
+            posted_votes: Vec::new(),
+        }
+    }
+}
+
+/// In-memory [`ApiClient`] with scripted responses, for tests.
+///
+/// Clones share their state, so a test can keep one to inspect the votes
+/// posted by the component it rendered with another.
+#[derive(Clone, Default)]
+pub(crate) struct FakeClient(Rc<RefCell<FakeState>>);
+
+impl FakeClient {
+    /// Number of votes of the user returned by `get_user_by_id`.
+    pub(crate) fn user_votes(self, votes: usize) -> Self {
+        self.0.borrow_mut().user_votes = votes;
+        self
+    }
+
+    pub(crate) fn user_error(self, error: RequestError) -> Self {
+        self.0.borrow_mut().user_error = Some(error);
+        self
+    }
+
+    /// Comparisons to return, in order, before falling back to
+    /// `Comparison::default()`.
+    pub(crate) fn comparisons(self, comparisons: Vec<Comparison>) -> Self {
+        self.0.borrow_mut().comparisons = comparisons.into();
+        self
+    }
+
+    pub(crate) fn comparison_error(self, error: RequestError) -> Self {
+        self.0.borrow_mut().comparison_error = Some(error);
+        self
+    }
+
+    pub(crate) fn comparison_latency(self, latency: Duration) -> Self {
+        self.0.borrow_mut().comparison_latency = latency;
+        self
+    }
+
+    /// Makes `comparison_is_valid` answer `false` for the comparison.
+    pub(crate) fn invalidate(self, comparison_id: &str) -> Self {
+        self.0
+            .borrow_mut()
+            .invalidated
+            .insert(comparison_id.to_string());
+        self
+    }
+
+    /// Makes `comparison_is_valid` fail for every comparison.
+    pub(crate) fn validity_error(self, error: RequestError) -> Self {
+        self.0.borrow_mut().validity_error = Some(error);
+        self
+    }
+
+    pub(crate) fn vote_error(self, error: RequestError) -> Self {
+        self.0.borrow_mut().vote_error = Some(error);
+        self
+    }
+
+    pub(crate) fn vote_latency(self, latency: Duration) -> Self {
+        self.0.borrow_mut().vote_latency = latency;
+        self
+    }
+
+    pub(crate) fn posted_votes(&self) -> Vec<Vote> {
+        self.0.borrow().posted_votes.clone()
+    }
+}
+
+impl ApiClient for FakeClient {
+    fn get_user_by_id(&self, id: String) -> ApiFuture<User> {
+        let state = self.0.borrow();
+        let response = match state.user_error.clone() {
+            Some(error) => Err(error),
+            None => Ok(User {
+                id,
+                votes: state.user_votes,
+                average_lambda: 0.65,
+            }),
+        };
+
+        Box::pin(async move { response })
+    }
+
+    #[allow(clippy::format_collect)]
+    fn generate_user(&self) -> ApiFuture<User> {
+        let mut state = self.0.borrow_mut();
+        let response = match state.user_error.clone() {
+            Some(error) => Err(error),
+            None => {
+                state.user_votes = 0;
+                let id: String = (0..16)
+                    .map(|_| format!("{:x}", rand::random::<u8>()))
+                    .collect();
+                Ok(User {
+                    id,
+                    votes: 0,
+                    average_lambda: 0.0,
+                })
+            },
+        };
+
+        Box::pin(async move { response })
+    }
+
+    fn get_comparison_for_user(
+        &self,
+        _user_id: String,
+        _dirname: String,
+        _exclude: Vec<String>,
+    ) -> ApiFuture<Comparison> {
+        let mut state = self.0.borrow_mut();
+        let latency = state.comparison_latency;
+        let response = match state.comparison_error.clone() {
+            Some(error) => Err(error),
+            None => Ok(state.comparisons.pop_front().unwrap_or_default()),
+        };
+
+        Box::pin(async move {
+            yew::platform::time::sleep(latency).await;
+            response
+        })
+    }
+
+    fn comparison_is_valid(&self, comparison_id: String) -> ApiFuture<bool> {
+        let state = self.0.borrow();
+        let response = match &state.validity_error {
+            Some(error) => Err(error.clone()),
+            None => Ok(!state.invalidated.contains(&comparison_id)),
+        };
+
+        Box::pin(async move { response })
+    }
+
+    fn post_vote(&self, vote: Vote) -> ApiFuture<()> {
+        let mut state = self.0.borrow_mut();
+        let not_in_comparison = || RequestError::Server {
+            code: None,
+            error: "image not in comparison".to_string(),
+        };
+        let in_comparison =
+            |image: &String| vote.comparison_images().contains(image);
+        let response = match (state.vote_error.clone(), vote.vote_value()) {
+            (Some(error), _) => Err(error),
+            (None, VoteValue::OneIsBetter(image)) => in_comparison(image)
+                .then_some(())
+                .ok_or_else(not_in_comparison),
+            (None, VoteValue::Ranking(images)) => images
+                .iter()
+                .all(in_comparison)
+                .then_some(())
+                .ok_or_else(not_in_comparison),
+            (None, VoteValue::Abx { a, b, .. }) => (in_comparison(a)
+                && in_comparison(b))
+            .then_some(())
+            .ok_or_else(not_in_comparison),
+            (None, VoteValue::BestWorst { best, worst }) => {
+                (best != worst && in_comparison(best) && in_comparison(worst))
+                    .then_some(())
+                    .ok_or_else(not_in_comparison)
+            },
+            (None, _) => Ok(()),
+        };
+        if response.is_ok() {
+            state.posted_votes.push(vote);
+        }
+        let latency = state.vote_latency;
+
+        Box::pin(async move {
+            if !latency.is_zero() {
+                yew::platform::time::sleep(latency).await;
+            }
+            response
+        })
+    }
+}
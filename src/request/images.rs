@@ -1,135 +1,422 @@
-#[cfg(test)]
-use std::sync::atomic::{
-    AtomicBool,
-    Ordering,
-};
+use std::rc::Rc;
 
+use futures::future;
 use serde::{
+    de::IgnoredAny,
     Deserialize,
     Serialize,
 };
 
-use super::Response;
-use crate::dom::{
-    console_error,
-    DOM,
+use super::{
+    config::ApiConfig,
+    read_response,
+    session::Session,
+    RequestError,
 };
+use crate::dom::DOM;
 
 #[derive(Clone, PartialEq, Deserialize)]
 pub(crate) struct Comparison {
     pub(crate) id: String,
     pub(crate) images: Vec<String>,
+    /// Pristine image the others are judged against, for double-stimulus
+    /// trials. Shown apart from them and never a valid answer.
+    #[serde(default)]
+    pub(crate) reference: Option<String>,
+    /// Known correct answer, for attention checks.
+    #[serde(default)]
+    pub(crate) catch_trial: Option<CatchTrial>,
 }
 
-pub(crate) async fn get_comparison_for_user(
-    user_id: String,
-    dirname: String,
-) -> Result<Comparison, ()> {
-    #[cfg(test)]
-    if cfg!(test) {
-        // sleep a bit to allow test to see the loading status
-        crate::wasm_sleep_in_ms(50).await;
+/// Attention check (catch trial): a comparison whose answer is obvious, to
+/// tell participants who answer at random.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum CatchTrial {
+    /// The image is obviously better than the others.
+    Better(String),
+    /// The images are identical.
+    Identical,
+}
+
+impl CatchTrial {
+    /// Whether the answer is the correct one; `None` when the kind of
+    /// answer cannot tell, e.g. a rating.
+    pub(crate) fn passed_by(&self, vote_value: &VoteValue) -> Option<bool> {
+        match (self, vote_value) {
+            (CatchTrial::Better(image), VoteValue::OneIsBetter(answer)) => {
+                Some(answer == image)
+            },
+            (CatchTrial::Better(image), VoteValue::Ranking(images)) => {
+                Some(images.first() == Some(image))
+            },
+            (CatchTrial::Better(image), VoteValue::BestWorst { best, .. }) => {
+                Some(best == image)
+            },
+            (CatchTrial::Better(_), VoteValue::Equal) => Some(false),
+            (CatchTrial::Better(_), VoteValue::Different) => Some(true),
+            (CatchTrial::Identical, VoteValue::Equal) => Some(true),
+            (CatchTrial::Identical, VoteValue::Different) => Some(false),
+            _ => None,
+        }
+    }
+}
 
-        return match GET_IMAGES_RETURNS_OK.load(Ordering::SeqCst) {
-            true => Ok(Comparison::default()),
-            false => Err(()),
+impl Comparison {
+    /// Whether the answer only refers to images of the comparison other
+    /// than its reference.
+    pub(crate) fn accepts(&self, vote_value: &VoteValue) -> bool {
+        let is_candidate = |image: &String| {
+            self.images.contains(image)
+                && self.reference.as_ref() != Some(image)
         };
+        match vote_value {
+            VoteValue::OneIsBetter(image) => is_candidate(image),
+            // every candidate, once
+            VoteValue::Ranking(images) => {
+                let candidates = self
+                    .images
+                    .iter()
+                    .filter(|image| is_candidate(image))
+                    .count();
+                images.len() == candidates
+                    && images.iter().enumerate().all(|(index, image)| {
+                        is_candidate(image) && !images[..index].contains(image)
+                    })
+            },
+            VoteValue::Abx {
+                a, b, x, answer, ..
+            } => {
+                let is_a_or_b = |image: &String| image == a || image == b;
+                a != b
+                    && is_candidate(a)
+                    && is_candidate(b)
+                    && is_a_or_b(x)
+                    && is_a_or_b(answer)
+            },
+            VoteValue::BestWorst { best, worst } => {
+                best != worst && is_candidate(best) && is_candidate(worst)
+            },
+            VoteValue::Artifacts(marks) => {
+                marks.iter().all(ArtifactMark::is_on_image)
+            },
+            _ => true,
+        }
     }
+}
 
-    let comparison = gloo_net::http::Request::get(&format!(
-        "/api/user/{user_id}/comparison?dirname={dirname}"
-    ))
-    .send()
-    .await
-    .map_err(|error| console_error!(error.to_string()))?
-    .json::<Response<Comparison, String>>()
-    .await
-    .map_err(|error| console_error!(error.to_string()))?
-    .as_result()
-    .map_err(|error| console_error!(error))?;
-
-    // pre-load images
-    for image in &comparison.images {
-        gloo_net::http::Request::get(&image)
-            .send()
-            .await
-            .map_err(|error| console_error!(error.to_string()))?
-            .binary()
-            .await
-            .map_err(|error| console_error!(error.to_string()))?;
+/// Path of the next comparison for the user, in the stimulus set
+/// `dirname`. It and the `exclude` ids are encoded to be kept whole in the
+/// query.
+fn comparison_path(user_id: &str, dirname: &str, exclude: &[String]) -> String {
+    let encode =
+        |value: &str| String::from(js_sys::encode_uri_component(value));
+    let mut path =
+        format!("/api/user/{user_id}/comparison?dirname={}", encode(dirname));
+    if !exclude.is_empty() {
+        let exclude = exclude
+            .iter()
+            .map(|id| encode(id))
+            .collect::<Vec<_>>()
+            .join(",");
+        path.push_str(&format!("&exclude={exclude}"));
     }
 
+    path
+}
+
+/// Fetches the next comparison for the user and preloads its images.
+///
+/// Comparisons whose id is in `exclude` (e.g. ones already shown or
+/// buffered) are not to be returned.
+pub(super) async fn get_comparison_for_user(
+    config: Rc<ApiConfig>,
+    user_id: String,
+    dirname: String,
+    exclude: Vec<String>,
+) -> Result<Comparison, RequestError> {
+    let path = comparison_path(&user_id, &dirname, &exclude);
+    let response = gloo_net::http::Request::get(&config.api_url(&path))
+        .send()
+        .await?;
+    let mut comparison: Comparison = read_response(response).await?;
+    comparison.images = comparison
+        .images
+        .iter()
+        .map(|image| config.image_url(image))
+        .collect();
+    comparison.reference = comparison
+        .reference
+        .map(|reference| config.image_url(&reference));
+    if let Some(CatchTrial::Better(image)) = &mut comparison.catch_trial {
+        *image = config.image_url(image);
+    }
+
+    // pre-load images in parallel
+    future::try_join_all(
+        comparison
+            .images
+            .iter()
+            .chain(&comparison.reference)
+            .map(|image| preload_image(image)),
+    )
+    .await?;
+
     Ok(comparison)
 }
 
+async fn preload_image(image: &str) -> Result<(), RequestError> {
+    let response = gloo_net::http::Request::get(image).send().await?;
+    if !response.ok() {
+        return Err(RequestError::Status {
+            code: response.status(),
+            error: None,
+        });
+    }
+    response.binary().await?;
+
+    Ok(())
+}
+
+/// Whether the API still accepts votes on the comparison, i.e. it has not
+/// been invalidated since it was fetched.
+pub(super) async fn comparison_is_valid(
+    config: Rc<ApiConfig>,
+    comparison_id: String,
+) -> Result<bool, RequestError> {
+    let response = gloo_net::http::Request::get(
+        &config.api_url(&format!("/api/comparison/{comparison_id}")),
+    )
+    .send()
+    .await?;
+
+    read_validity(response).await
+}
+
+/// `404 Not Found` and `410 Gone` tell the comparison no longer accepts
+/// votes; any other answer than a valid envelope is an error, as the
+/// comparison cannot be confirmed valid.
+async fn read_validity(
+    response: gloo_net::http::Response,
+) -> Result<bool, RequestError> {
+    match response.status() {
+        404 | 410 => Ok(false),
+        _ => read_response::<IgnoredAny>(response).await.map(|_| true),
+    }
+}
+
 impl Default for Comparison {
     fn default() -> Self {
         Self {
             id: String::default(),
             images: vec![String::default(), String::default()],
+            reference: None,
+            catch_trial: None,
         }
     }
 }
 
-#[cfg(test)]
-pub(crate) static GET_IMAGES_RETURNS_OK: AtomicBool = AtomicBool::new(true);
-
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub(crate) enum VoteValue {
     #[serde(rename = "equal")]
     Equal,
     #[serde(rename = "different")]
     Different,
+    #[serde(rename = "rating")]
+    Rating(u8),
+    #[serde(rename = "ranking")]
+    Ranking(Vec<String>),
+    /// ABX discrimination: `x` is one of `a` and `b`, and `answer` the one
+    /// the participant matched it with.
+    #[serde(rename = "abx")]
+    Abx {
+        a: String,
+        b: String,
+        x: String,
+        answer: String,
+        /// Order A, B and X were revealed in, e.g. `"BXA"`.
+        reveal_order: String,
+    },
+    /// Best–worst scaling: the best and the worst image of the comparison,
+    /// which are never the same.
+    #[serde(rename = "best_worst")]
+    BestWorst { best: String, worst: String },
+    /// Where artifacts are visible on the (single) image; none when the
+    /// participant sees none.
+    #[serde(rename = "artifacts")]
+    Artifacts(Vec<ArtifactMark>),
     #[serde(untagged)]
     OneIsBetter(String),
 }
 
-#[derive(Serialize, Deserialize)]
+impl VoteValue {
+    /// Applies `f` to every image the vote refers to.
+    fn map_images(self, f: impl Fn(&str) -> String) -> Self {
+        match self {
+            VoteValue::Ranking(images) => VoteValue::Ranking(
+                images.iter().map(|image| f(image)).collect(),
+            ),
+            VoteValue::OneIsBetter(image) => VoteValue::OneIsBetter(f(&image)),
+            VoteValue::Abx {
+                a,
+                b,
+                x,
+                answer,
+                reveal_order,
+            } => VoteValue::Abx {
+                a: f(&a),
+                b: f(&b),
+                x: f(&x),
+                answer: f(&answer),
+                reveal_order,
+            },
+            VoteValue::BestWorst { best, worst } => VoteValue::BestWorst {
+                best: f(&best),
+                worst: f(&worst),
+            },
+            vote_value => vote_value,
+        }
+    }
+}
+
+/// Point of an image marked by the participant, normalized to the image
+/// (0 to 1 from its top-left corner) whatever size it was rendered at.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct ArtifactMark {
+    pub(crate) x: f64,
+    pub(crate) y: f64,
+}
+
+impl ArtifactMark {
+    pub(crate) fn is_on_image(&self) -> bool {
+        (0.0..=1.0).contains(&self.x) && (0.0..=1.0).contains(&self.y)
+    }
+}
+
+/// Part of the images on screen when the participant voted, in coordinates
+/// normalized to the frame the images are fitted in (0 to 1 from its
+/// top-left corner).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct Viewport {
+    /// Magnification relative to the fitted images.
+    pub(crate) zoom: f64,
+    /// Whether image pixels were shown 1:1 on device pixels.
+    pub(crate) pixel_exact: bool,
+    pub(crate) x: f64,
+    pub(crate) y: f64,
+    pub(crate) width: f64,
+    pub(crate) height: f64,
+}
+
+/// When the comparison was on screen and answered, in milliseconds on the
+/// page's high-resolution clock (`performance.now()`).
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct Timing {
+    /// Every image of the comparison was decoded.
+    pub(crate) decoded_ms: Option<f64>,
+    /// The first frame showing the decoded images was painted.
+    pub(crate) painted_ms: Option<f64>,
+    /// The participant made their choice.
+    pub(crate) answered_ms: f64,
+    /// From painted to answered.
+    pub(crate) response_time_ms: Option<f64>,
+}
+
+/// How often, and how long, the participant left the comparison on screen
+/// before answering.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct Interruptions {
+    /// Times the page was hidden or the window lost the focus.
+    pub(crate) count: usize,
+    /// Milliseconds the page was hidden (e.g. in another tab).
+    pub(crate) hidden_ms: f64,
+}
+
+/// How the images of a comparison were laid out on screen.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Presentation {
+    /// Next to (or, on narrow screens, above) each other.
+    #[default]
+    SideBySide,
+    /// Overlaid, with a divider dragged to reveal one or the other.
+    Wipe,
+    /// In the same place, shown one at a time in turn.
+    Flicker,
+}
+
+impl Presentation {
+    /// Name used in element ids and markdown file names.
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            Presentation::SideBySide => "side_by_side",
+            Presentation::Wipe => "wipe",
+            Presentation::Flicker => "flicker",
+        }
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
 pub(crate) struct Vote {
+    idempotency_key: String,
     comparison_id: String,
     #[serde(skip)]
     _comparison_images: Vec<String>,
     user_id: String,
     vote_value: VoteValue,
+    presentation: Presentation,
+    viewport: Option<Viewport>,
+    exposure_ms: Option<f64>,
+    confidence: Option<u8>,
+    /// Whether the answer to a catch trial was correct.
+    catch_trial_passed: Option<bool>,
+    timing: Option<Timing>,
+    interruptions: Option<Interruptions>,
+    session: Option<Session>,
     user_agent: Option<String>,
     language: Option<String>,
 }
 
-pub(crate) async fn post_vote(vote: Vote) -> Result<(), ()> {
-    #[cfg(test)]
-    if cfg!(test) {
-        return match vote.vote_value {
-            VoteValue::OneIsBetter(image) => vote
-                ._comparison_images
-                .contains(&image)
-                .then_some(())
-                .ok_or(()),
-            _ => Ok(()),
-        };
-    }
-
-    let _ = gloo_net::http::Request::post("/api/vote")
-        .json::<Vote>(&vote)
-        .map_err(|error| console_error!(error.to_string()))?
+pub(super) async fn post_vote(
+    config: Rc<ApiConfig>,
+    mut vote: Vote,
+) -> Result<(), RequestError> {
+    // the API knows the images by the paths it gave
+    vote.vote_value =
+        vote.vote_value.map_images(|image| config.image_path(image));
+    let response = gloo_net::http::Request::post(&config.api_url("/api/vote"))
+        .json::<Vote>(&vote)?
         .send()
-        .await
-        .map_err(|error| console_error!(error.to_string()))?
-        .json::<Response<Vote, String>>()
-        .await
-        .map_err(|error| console_error!(error.to_string()))?
-        .as_result()
-        .map_err(|error| console_error!(error))?;
+        .await?;
 
-    Ok(())
+    read_vote_ack(response).await
+}
+
+/// Checks the API accepted the vote. What it echoes back is not needed, so
+/// it is not parsed as a [`Vote`], which fields it may not all return.
+async fn read_vote_ack(
+    response: gloo_net::http::Response,
+) -> Result<(), RequestError> {
+    read_response::<IgnoredAny>(response).await.map(|_| ())
 }
 
 impl Vote {
     pub(crate) fn build(comparison: Comparison) -> Self {
+        let idempotency_key = DOM::random_uuid();
         Self {
+            idempotency_key,
             comparison_id: comparison.id,
             _comparison_images: comparison.images,
             user_id: String::default(),
             vote_value: VoteValue::OneIsBetter(String::default()),
+            presentation: Presentation::default(),
+            viewport: None,
+            exposure_ms: None,
+            confidence: None,
+            catch_trial_passed: None,
+            timing: None,
+            interruptions: None,
+            session: None,
             user_agent: DOM::user_agent(),
             language: DOM::language(),
         }
@@ -144,6 +431,113 @@ impl Vote {
         self.vote_value = vote_value;
         self
     }
+
+    pub(crate) fn presentation(mut self, presentation: Presentation) -> Self {
+        self.presentation = presentation;
+        self
+    }
+
+    /// Zoom and pan of the images, for experiments that allow them.
+    pub(crate) fn viewport(mut self, viewport: Option<Viewport>) -> Self {
+        self.viewport = viewport;
+        self
+    }
+
+    /// How long the comparison was on screen, in milliseconds, for limited
+    /// exposures.
+    pub(crate) fn exposure(mut self, exposure_ms: Option<f64>) -> Self {
+        self.exposure_ms = exposure_ms;
+        self
+    }
+
+    /// Confidence of the participant in the answer, on the experiment's
+    /// scale, for experiments asking for it.
+    pub(crate) fn confidence(mut self, confidence: Option<u8>) -> Self {
+        self.confidence = confidence;
+        self
+    }
+
+    /// Result of the catch trial, for comparisons that are one.
+    pub(crate) fn catch_trial(mut self, passed: Option<bool>) -> Self {
+        self.catch_trial_passed = passed;
+        self
+    }
+
+    /// How often, and how long, the participant left the comparison.
+    pub(crate) fn interruptions(
+        mut self,
+        interruptions: Option<Interruptions>,
+    ) -> Self {
+        self.interruptions = interruptions;
+        self
+    }
+
+    /// When the comparison was displayed and answered.
+    pub(crate) fn timing(mut self, timing: Option<Timing>) -> Self {
+        self.timing = timing;
+        self
+    }
+
+    /// Display conditions the comparison was answered in.
+    pub(crate) fn session(mut self, session: Option<Session>) -> Self {
+        self.session = session;
+        self
+    }
+
+    /// Client-generated key letting the API discard replayed duplicates.
+    pub(crate) fn idempotency_key(&self) -> &str {
+        &self.idempotency_key
+    }
+
+    #[cfg(test)]
+    pub(crate) fn vote_value(&self) -> &VoteValue {
+        &self.vote_value
+    }
+
+    #[cfg(test)]
+    pub(crate) fn recorded_presentation(&self) -> Presentation {
+        self.presentation
+    }
+
+    #[cfg(test)]
+    pub(crate) fn recorded_exposure(&self) -> Option<f64> {
+        self.exposure_ms
+    }
+
+    #[cfg(test)]
+    pub(crate) fn recorded_confidence(&self) -> Option<u8> {
+        self.confidence
+    }
+
+    #[cfg(test)]
+    pub(crate) fn recorded_catch_trial(&self) -> Option<bool> {
+        self.catch_trial_passed
+    }
+
+    #[cfg(test)]
+    pub(crate) fn recorded_interruptions(&self) -> Option<&Interruptions> {
+        self.interruptions.as_ref()
+    }
+
+    #[cfg(test)]
+    pub(crate) fn recorded_timing(&self) -> Option<&Timing> {
+        self.timing.as_ref()
+    }
+
+    #[cfg(test)]
+    pub(crate) fn recorded_viewport(&self) -> Option<&Viewport> {
+        self.viewport.as_ref()
+    }
+
+    #[cfg(test)]
+    pub(crate) fn recorded_session(&self) -> Option<&Session> {
+        self.session.as_ref()
+    }
+
+    #[cfg(test)]
+    pub(super) fn comparison_images(&self) -> &[String] {
+        &self._comparison_images
+    }
 }
 
 #[cfg(test)]
@@ -154,7 +548,16 @@ mod tests {
     };
 
     use super::{
+        comparison_path,
+        read_validity,
+        read_vote_ack,
+        ArtifactMark,
+        CatchTrial,
         Comparison,
+        Presentation,
+        Session,
+        Timing,
+        Viewport,
         Vote,
         VoteValue,
     };
@@ -168,9 +571,68 @@ mod tests {
                 "/image/path/0.png".to_string(),
                 "/image/path/1.png".to_string(),
             ],
+            reference: None,
+            catch_trial: None,
         }
     }
 
+    #[wasm_bindgen_test]
+    async fn vote_is_acknowledged_whatever_the_api_echoes() {
+        let response = gloo_net::http::Response::builder()
+            .status(200)
+            .json(&serde_json::json!({
+                "data": { "comparison_id": "5555", "vote_value": "equal" },
+            }))
+            .expect("response to be buildable");
+
+        assert_eq!(read_vote_ack(response).await, Ok(()));
+    }
+
+    #[wasm_bindgen_test]
+    async fn missing_and_gone_comparisons_are_invalid() {
+        let response = |status: u16| {
+            gloo_net::http::Response::builder()
+                .status(status)
+                .json(&serde_json::json!({ "error": "Comparison gone" }))
+                .expect("response to be buildable")
+        };
+
+        assert_eq!(read_validity(response(404)).await, Ok(false));
+        assert_eq!(read_validity(response(410)).await, Ok(false));
+        // e.g. an API without the route cannot confirm it
+        assert!(read_validity(response(405)).await.is_err());
+    }
+
+    #[wasm_bindgen_test]
+    async fn valid_comparison_is_confirmed() {
+        let response = gloo_net::http::Response::builder()
+            .status(200)
+            .json(&serde_json::json!({ "data": { "id": "a" } }))
+            .expect("response to be buildable");
+
+        assert_eq!(read_validity(response).await, Ok(true));
+    }
+
+    #[wasm_bindgen_test]
+    fn comparison_path_encodes_dirname() {
+        assert_eq!(
+            comparison_path("4444", "a&b #1", &[]),
+            "/api/user/4444/comparison?dirname=a%26b%20%231"
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn comparison_path_encodes_excluded_ids() {
+        assert_eq!(
+            comparison_path(
+                "4444",
+                "set",
+                &["a,b".to_string(), "c&d".to_string()]
+            ),
+            "/api/user/4444/comparison?dirname=set&exclude=a%2Cb,c%26d"
+        );
+    }
+
     #[wasm_bindgen_test]
     fn vote_contains_user_agent() {
         let vote: Vote = Vote::build(test_comparison())
@@ -180,6 +642,21 @@ mod tests {
         assert_eq!(vote.user_agent, DOM::user_agent());
     }
 
+    #[wasm_bindgen_test]
+    fn vote_contains_session() {
+        let comparison = test_comparison();
+        let session = Session::collect(comparison.images.len());
+        let vote: Vote = Vote::build(comparison)
+            .user("44444444444444444444444444444444".to_string())
+            .vote(VoteValue::OneIsBetter("/image/path/0.png".to_string()))
+            .session(session);
+
+        let session = vote.session.expect("Vote to contain the session");
+        assert_eq!(session.device_pixel_ratio, DOM::device_pixel_ratio());
+        // the images are not on screen
+        assert_eq!(session.stimuli, vec![None, None]);
+    }
+
     #[wasm_bindgen_test]
     fn vote_contains_language() {
         let vote: Vote = Vote::build(test_comparison())
@@ -210,6 +687,224 @@ mod tests {
         assert_eq!(vote.user_id, "44444444444444444444444444444444");
     }
 
+    #[wasm_bindgen_test]
+    fn vote_contains_viewport() {
+        let viewport = Viewport {
+            zoom: 2.0,
+            pixel_exact: false,
+            x: 0.25,
+            y: 0.25,
+            width: 0.5,
+            height: 0.5,
+        };
+        let vote: Vote = Vote::build(test_comparison())
+            .user("44444444444444444444444444444444".to_string())
+            .vote(VoteValue::OneIsBetter("/image/path/0.png".to_string()))
+            .viewport(Some(viewport.clone()));
+
+        assert_eq!(vote.viewport, Some(viewport));
+    }
+
+    #[wasm_bindgen_test]
+    fn vote_contains_presentation() {
+        let vote: Vote = Vote::build(test_comparison())
+            .user("44444444444444444444444444444444".to_string())
+            .vote(VoteValue::Equal)
+            .presentation(Presentation::Wipe);
+
+        let value =
+            serde_json::to_value(vote).expect("Vote to be serializable");
+
+        assert_eq!(value["presentation"], "wipe");
+    }
+
+    #[wasm_bindgen_test]
+    fn vote_contains_timing() {
+        let vote = Vote::build(test_comparison())
+            .vote(VoteValue::Equal)
+            .timing(Some(Timing {
+                decoded_ms: Some(1000.0),
+                painted_ms: Some(1020.0),
+                answered_ms: 2500.0,
+                response_time_ms: Some(1480.0),
+            }));
+
+        let value =
+            serde_json::to_value(vote).expect("Vote to be serializable");
+
+        assert_eq!(
+            value["timing"],
+            serde_json::json!({
+                "decoded_ms": 1000.0,
+                "painted_ms": 1020.0,
+                "answered_ms": 2500.0,
+                "response_time_ms": 1480.0,
+            })
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn reference_is_never_a_valid_answer() {
+        let comparison = Comparison {
+            reference: Some("/image/path/reference.png".to_string()),
+            ..test_comparison()
+        };
+
+        assert!(comparison
+            .accepts(&VoteValue::OneIsBetter("/image/path/0.png".to_string())));
+        assert!(!comparison.accepts(&VoteValue::OneIsBetter(
+            "/image/path/reference.png".to_string()
+        )));
+        assert!(!comparison.accepts(&VoteValue::Ranking(vec![
+            "/image/path/reference.png".to_string(),
+            "/image/path/0.png".to_string(),
+        ])));
+    }
+
+    #[wasm_bindgen_test]
+    fn ranking_must_hold_every_image_once() {
+        let ranking = |images: &[&str]| {
+            VoteValue::Ranking(images.iter().map(|x| x.to_string()).collect())
+        };
+        let comparison = test_comparison();
+
+        assert!(comparison
+            .accepts(&ranking(&["/image/path/1.png", "/image/path/0.png"])));
+        assert!(!comparison.accepts(&ranking(&["/image/path/1.png"])));
+        assert!(!comparison
+            .accepts(&ranking(&["/image/path/1.png", "/image/path/1.png"])));
+    }
+
+    #[wasm_bindgen_test]
+    fn abx_answer_must_be_a_or_b() {
+        let abx = |x: &str, answer: &str| VoteValue::Abx {
+            a: "/image/path/0.png".to_string(),
+            b: "/image/path/1.png".to_string(),
+            x: x.to_string(),
+            answer: answer.to_string(),
+            reveal_order: "ABX".to_string(),
+        };
+
+        let comparison = test_comparison();
+
+        assert!(
+            comparison.accepts(&abx("/image/path/0.png", "/image/path/1.png"))
+        );
+        assert!(
+            !comparison.accepts(&abx("/image/path/0.png", "/image/path/2.png"))
+        );
+        assert!(
+            !comparison.accepts(&abx("/image/path/2.png", "/image/path/0.png"))
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn best_and_worst_must_be_distinct_images() {
+        let best_worst = |best: &str, worst: &str| VoteValue::BestWorst {
+            best: best.to_string(),
+            worst: worst.to_string(),
+        };
+
+        let comparison = test_comparison();
+
+        assert!(comparison
+            .accepts(&best_worst("/image/path/0.png", "/image/path/1.png")));
+        assert!(!comparison
+            .accepts(&best_worst("/image/path/0.png", "/image/path/0.png")));
+        assert!(!comparison
+            .accepts(&best_worst("/image/path/0.png", "/image/path/2.png")));
+    }
+
+    #[wasm_bindgen_test]
+    fn artifact_marks_must_be_on_the_image() {
+        let comparison = test_comparison();
+
+        assert!(comparison.accepts(&VoteValue::Artifacts(Vec::new())));
+        assert!(comparison.accepts(&VoteValue::Artifacts(vec![
+            ArtifactMark { x: 0.0, y: 1.0 }
+        ])));
+        assert!(!comparison.accepts(&VoteValue::Artifacts(vec![
+            ArtifactMark { x: 1.5, y: 0.5 }
+        ])));
+    }
+
+    #[wasm_bindgen_test]
+    fn catch_trial_is_deserializable() {
+        let better = serde_json::json!({
+            "id": "55555555555555555555555555555555",
+            "images": ["/image/path/0.png", "/image/path/1.png"],
+            "catch_trial": { "better": "/image/path/1.png" },
+        });
+        let identical = serde_json::json!({
+            "id": "55555555555555555555555555555555",
+            "images": ["/image/path/0.png", "/image/path/0.png"],
+            "catch_trial": "identical",
+        });
+
+        let better = serde_json::from_value::<Comparison>(better)
+            .expect("Comparison to be deserializable");
+        let identical = serde_json::from_value::<Comparison>(identical)
+            .expect("Comparison to be deserializable");
+
+        assert_eq!(
+            better.catch_trial,
+            Some(CatchTrial::Better("/image/path/1.png".to_string()))
+        );
+        assert_eq!(identical.catch_trial, Some(CatchTrial::Identical));
+    }
+
+    #[wasm_bindgen_test]
+    fn catch_trial_is_passed_by_the_obvious_answer() {
+        let better = CatchTrial::Better("/image/path/1.png".to_string());
+
+        assert_eq!(
+            better.passed_by(&VoteValue::OneIsBetter(
+                "/image/path/1.png".to_string()
+            )),
+            Some(true)
+        );
+        assert_eq!(
+            better.passed_by(&VoteValue::OneIsBetter(
+                "/image/path/0.png".to_string()
+            )),
+            Some(false)
+        );
+        assert_eq!(better.passed_by(&VoteValue::Rating(5)), None);
+        assert_eq!(
+            CatchTrial::Identical.passed_by(&VoteValue::Equal),
+            Some(true)
+        );
+        assert_eq!(
+            CatchTrial::Identical.passed_by(&VoteValue::Different),
+            Some(false)
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn vote_contains_catch_trial_result() {
+        let vote = Vote::build(test_comparison())
+            .vote(VoteValue::Equal)
+            .catch_trial(Some(false));
+
+        let value =
+            serde_json::to_value(vote).expect("Vote to be serializable");
+
+        assert_eq!(value["catch_trial_passed"], false);
+    }
+
+    #[wasm_bindgen_test]
+    fn comparison_without_reference_is_deserializable() {
+        let value = serde_json::json!({
+            "id": "55555555555555555555555555555555",
+            "images": ["/image/path/0.png", "/image/path/1.png"],
+        });
+
+        let comparison = serde_json::from_value::<Comparison>(value)
+            .expect("Comparison to be deserializable");
+
+        assert_eq!(comparison.reference, None);
+    }
+
     #[wasm_bindgen_test]
     fn vote_contains_comparison_id() {
         let vote: Vote = Vote::build(test_comparison())
@@ -219,6 +914,14 @@ mod tests {
         assert_eq!(vote.comparison_id, "55555555555555555555555555555555");
     }
 
+    #[wasm_bindgen_test]
+    fn votes_have_distinct_idempotency_keys() {
+        let first = Vote::build(test_comparison());
+        let second = Vote::build(test_comparison());
+
+        assert_ne!(first.idempotency_key(), second.idempotency_key());
+    }
+
     #[wasm_bindgen_test]
     fn vote_is_serializable() {
         let vote: Vote = Vote::build(test_comparison())
@@ -228,6 +931,30 @@ mod tests {
         assert!(serde_json::to_value(vote).is_ok())
     }
 
+    #[wasm_bindgen_test]
+    fn rating_vote_value_is_serialized_with_its_score() {
+        let value = serde_json::to_value(VoteValue::Rating(4))
+            .expect("VoteValue to be serializable");
+
+        assert_eq!(value, serde_json::json!({ "rating": 4 }));
+    }
+
+    #[wasm_bindgen_test]
+    fn ranking_vote_value_is_serialized_with_its_order() {
+        let value = serde_json::to_value(VoteValue::Ranking(vec![
+            "/image/path/1.png".to_string(),
+            "/image/path/0.png".to_string(),
+        ]))
+        .expect("VoteValue to be serializable");
+
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "ranking": ["/image/path/1.png", "/image/path/0.png"]
+            })
+        );
+    }
+
     #[wasm_bindgen_test]
     fn comparison_is_deserializable() {
         let value = serde_json::json!({
@@ -237,4 +964,96 @@ mod tests {
 
         assert!(serde_json::from_value::<Comparison>(value).is_ok());
     }
+
+    #[wasm_bindgen_test]
+    fn abx_vote_value_is_serialized_with_its_answer() {
+        let value = serde_json::to_value(VoteValue::Abx {
+            a: "/image/path/0.png".to_string(),
+            b: "/image/path/1.png".to_string(),
+            x: "/image/path/1.png".to_string(),
+            answer: "/image/path/0.png".to_string(),
+            reveal_order: "BAX".to_string(),
+        })
+        .expect("VoteValue to be serializable");
+
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "abx": {
+                    "a": "/image/path/0.png",
+                    "b": "/image/path/1.png",
+                    "x": "/image/path/1.png",
+                    "answer": "/image/path/0.png",
+                    "reveal_order": "BAX",
+                },
+            })
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn best_worst_vote_value_is_serialized_with_both_images() {
+        let value = serde_json::to_value(VoteValue::BestWorst {
+            best: "/image/path/2.png".to_string(),
+            worst: "/image/path/0.png".to_string(),
+        })
+        .expect("VoteValue to be serializable");
+
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "best_worst": {
+                    "best": "/image/path/2.png",
+                    "worst": "/image/path/0.png",
+                },
+            })
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn artifacts_vote_value_is_serialized_with_its_marks() {
+        let value =
+            serde_json::to_value(VoteValue::Artifacts(vec![ArtifactMark {
+                x: 0.25,
+                y: 0.5,
+            }]))
+            .expect("VoteValue to be serializable");
+
+        assert_eq!(
+            value,
+            serde_json::json!({ "artifacts": [{ "x": 0.25, "y": 0.5 }] })
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn vote_value_images_can_be_mapped() {
+        let prefix = |image: &str| format!("https://images.example.com{image}");
+
+        assert_eq!(
+            VoteValue::OneIsBetter("/0.png".to_string()).map_images(prefix),
+            VoteValue::OneIsBetter(
+                "https://images.example.com/0.png".to_string()
+            )
+        );
+        assert_eq!(
+            VoteValue::Ranking(vec!["/1.png".to_string()]).map_images(prefix),
+            VoteValue::Ranking(vec![
+                "https://images.example.com/1.png".to_string()
+            ])
+        );
+        assert_eq!(
+            VoteValue::BestWorst {
+                best: "/0.png".to_string(),
+                worst: "/1.png".to_string(),
+            }
+            .map_images(prefix),
+            VoteValue::BestWorst {
+                best: "https://images.example.com/0.png".to_string(),
+                worst: "https://images.example.com/1.png".to_string(),
+            }
+        );
+        assert_eq!(
+            VoteValue::Rating(3).map_images(prefix),
+            VoteValue::Rating(3)
+        );
+    }
 }
@@ -1,13 +1,38 @@
+pub(crate) mod client;
+pub(crate) mod config;
+pub(crate) mod error;
+#[cfg(test)]
+pub(crate) mod fake;
 pub(crate) mod images;
+pub(crate) mod session;
 pub(crate) mod user;
+pub(crate) mod vote_queue;
 
-use serde::Deserialize;
+use serde::{
+    de::{
+        DeserializeOwned,
+        IgnoredAny,
+    },
+    Deserialize,
+};
 
+#[cfg(test)]
+pub(crate) use self::fake::FakeClient;
 pub(crate) use self::{
+    client::{
+        use_api,
+        Api,
+        GlooClient,
+    },
+    config::ApiConfig,
+    error::RequestError,
     images::{
-        get_comparison_for_user,
-        post_vote,
+        ArtifactMark,
         Comparison,
+        Interruptions,
+        Presentation,
+        Timing,
+        Viewport,
         Vote,
         VoteValue,
     },
@@ -17,22 +42,55 @@ pub(crate) use self::{
     },
 };
 
+/// Envelope wrapping every API response: exactly one of `data` or `error`
+/// is expected, optionally with a status `code`.
 #[derive(Deserialize)]
 pub(crate) struct Response<T, E> {
     pub(crate) data: Option<T>,
     pub(crate) error: Option<E>,
+    #[serde(default)]
+    pub(crate) code: Option<u16>,
 }
 
-impl<T, E> Response<T, E> {
-    pub(crate) fn as_result(self) -> Result<T, E> {
+impl<T, E: ToString> Response<T, E> {
+    #[allow(clippy::wrong_self_convention)]
+    pub(crate) fn as_result(self) -> Result<T, RequestError> {
         match (self.data, self.error) {
-            (Some(data), _) => Ok(data),
-            (_, Some(error)) => Err(error),
-            _ => unreachable!(),
+            (Some(data), None) => Ok(data),
+            (None, Some(error)) => Err(RequestError::Server {
+                code: self.code,
+                error: error.to_string(),
+            }),
+            (Some(_), Some(_)) => Err(RequestError::InvalidEnvelope(
+                "both `data` and `error` are present".to_string(),
+            )),
+            (None, None) => Err(RequestError::InvalidEnvelope(
+                "neither `data` nor `error` is present".to_string(),
+            )),
         }
     }
 }
 
+/// Reads the [`Response`] envelope out of an API response, turning non-2xx
+/// statuses and `error` envelopes into a [`RequestError`].
+pub(crate) async fn read_response<T: DeserializeOwned>(
+    response: gloo_net::http::Response,
+) -> Result<T, RequestError> {
+    if !response.ok() {
+        let error = response
+            .json::<Response<IgnoredAny, String>>()
+            .await
+            .ok()
+            .and_then(|envelope| envelope.error);
+        return Err(RequestError::Status {
+            code: response.status(),
+            error,
+        });
+    }
+
+    response.json::<Response<T, String>>().await?.as_result()
+}
+
 #[cfg(test)]
 mod tests {
     use wasm_bindgen_test::{
@@ -40,7 +98,11 @@ mod tests {
         wasm_bindgen_test_configure,
     };
 
-    use super::Response;
+    use super::{
+        read_response,
+        RequestError,
+        Response,
+    };
     wasm_bindgen_test_configure!(run_in_browser);
 
     #[wasm_bindgen_test]
@@ -49,16 +111,18 @@ mod tests {
             "data": 0,
         });
 
-        assert!(serde_json::from_value::<Response<usize, ()>>(value).is_ok());
+        assert!(
+            serde_json::from_value::<Response<usize, String>>(value).is_ok()
+        );
     }
 
     #[wasm_bindgen_test]
     fn response_struct_is_deserializable_with_error_field() {
         let value = serde_json::json!({
-            "error": 0,
+            "error": "error",
         });
 
-        assert!(serde_json::from_value::<Response<(), usize>>(value).is_ok());
+        assert!(serde_json::from_value::<Response<(), String>>(value).is_ok());
     }
 
     #[wasm_bindgen_test]
@@ -67,7 +131,7 @@ mod tests {
             "data": 0,
         });
 
-        let response = serde_json::from_value::<Response<usize, ()>>(value)
+        let response = serde_json::from_value::<Response<usize, String>>(value)
             .expect("to be deserializable");
 
         assert!(response.as_result().is_ok());
@@ -76,12 +140,130 @@ mod tests {
     #[wasm_bindgen_test]
     fn response_struct_with_error_field_can_be_cast_as_result() {
         let value = serde_json::json!({
-            "error": 0,
+            "error": "error",
         });
 
-        let response = serde_json::from_value::<Response<(), usize>>(value)
+        let response = serde_json::from_value::<Response<(), String>>(value)
             .expect("to be deserializable");
 
         assert!(response.as_result().is_err());
     }
+
+    #[wasm_bindgen_test]
+    async fn read_response_returns_data_of_successful_response() {
+        let response = gloo_net::http::Response::builder()
+            .status(200)
+            .json(&serde_json::json!({ "data": 0 }))
+            .expect("response to be buildable");
+
+        assert_eq!(read_response::<usize>(response).await, Ok(0));
+    }
+
+    #[wasm_bindgen_test]
+    async fn read_response_reports_non_2xx_status_with_its_error() {
+        let response = gloo_net::http::Response::builder()
+            .status(404)
+            .json(&serde_json::json!({ "error": "User not found" }))
+            .expect("response to be buildable");
+
+        assert_eq!(
+            read_response::<usize>(response).await,
+            Err(RequestError::Status {
+                code: 404,
+                error: Some("User not found".to_string()),
+            })
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn read_response_reports_server_error_of_successful_response() {
+        let response = gloo_net::http::Response::builder()
+            .status(200)
+            .json(&serde_json::json!({ "error": "Invalid vote" }))
+            .expect("response to be buildable");
+
+        assert_eq!(
+            read_response::<usize>(response).await,
+            Err(RequestError::Server {
+                code: None,
+                error: "Invalid vote".to_string(),
+            })
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn read_response_reports_invalid_json() {
+        let response = gloo_net::http::Response::builder()
+            .status(200)
+            .body(Some("not json"))
+            .expect("response to be buildable");
+
+        assert!(matches!(
+            read_response::<usize>(response).await,
+            Err(RequestError::Json(_))
+        ));
+    }
+
+    #[wasm_bindgen_test]
+    fn response_struct_with_code_field_is_deserializable() {
+        let value = serde_json::json!({
+            "error": "User not found",
+            "code": 404,
+        });
+
+        let response = serde_json::from_value::<Response<(), String>>(value)
+            .expect("to be deserializable");
+
+        assert_eq!(
+            response.as_result(),
+            Err(RequestError::Server {
+                code: Some(404),
+                error: "User not found".to_string(),
+            })
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn empty_response_struct_is_an_invalid_envelope() {
+        let value = serde_json::json!({});
+
+        let response = serde_json::from_value::<Response<usize, String>>(value)
+            .expect("to be deserializable");
+
+        assert!(matches!(
+            response.as_result(),
+            Err(RequestError::InvalidEnvelope(_))
+        ));
+    }
+
+    #[wasm_bindgen_test]
+    fn response_struct_with_null_data_is_an_invalid_envelope() {
+        let value = serde_json::json!({
+            "data": null,
+        });
+
+        let response = serde_json::from_value::<Response<usize, String>>(value)
+            .expect("to be deserializable");
+
+        assert!(matches!(
+            response.as_result(),
+            Err(RequestError::InvalidEnvelope(_))
+        ));
+    }
+
+    #[wasm_bindgen_test]
+    fn response_struct_with_data_and_error_is_an_invalid_envelope() {
+        let value = serde_json::json!({
+            "data": 0,
+            "error": "error",
+        });
+
+        let response = serde_json::from_value::<Response<usize, String>>(value)
+            .expect("to be deserializable");
+
+        assert!(matches!(
+            response.as_result(),
+            Err(RequestError::InvalidEnvelope(_))
+        ));
+    }
 }
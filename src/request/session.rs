@@ -0,0 +1,217 @@
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use crate::dom::DOM;
+
+/// Display conditions the participant voted in.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct Session {
+    /// Size of the viewport, in CSS pixels.
+    pub(crate) viewport_width: f64,
+    pub(crate) viewport_height: f64,
+    pub(crate) device_pixel_ratio: f64,
+    pub(crate) color_gamut: Option<ColorGamut>,
+    pub(crate) dynamic_range: Option<DynamicRange>,
+    pub(crate) color_scheme: Option<ColorScheme>,
+    /// Size each image of the comparison was rendered at, in the order of
+    /// its images; `None` for those not on screen.
+    pub(crate) stimuli: Vec<Option<RenderedSize>>,
+}
+
+/// Widest gamut the display covers (`color-gamut` media feature).
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum ColorGamut {
+    Srgb,
+    P3,
+    Rec2020,
+}
+
+/// `dynamic-range` media feature.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum DynamicRange {
+    Standard,
+    High,
+}
+
+/// `prefers-color-scheme` media feature.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum ColorScheme {
+    Light,
+    Dark,
+}
+
+/// Size of an image on screen, in device pixels.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct RenderedSize {
+    pub(crate) width: f64,
+    pub(crate) height: f64,
+}
+
+impl Session {
+    /// Reads the display conditions, and the size the `images` stimuli
+    /// (`#image_to_compare_{index}`) are rendered at, from the page; `None`
+    /// outside of a browser window.
+    pub(crate) fn collect(images: usize) -> Option<Self> {
+        let (viewport_width, viewport_height) = DOM::viewport_size()?;
+        let device_pixel_ratio = DOM::device_pixel_ratio();
+
+        Some(Self {
+            viewport_width,
+            viewport_height,
+            device_pixel_ratio,
+            color_gamut: ColorGamut::matching(),
+            dynamic_range: DynamicRange::matching(),
+            color_scheme: ColorScheme::matching(),
+            stimuli: (0..images)
+                .map(|index| {
+                    let id = format!("image_to_compare_{index}");
+                    DOM::rendered_image_size(&id).map(|(width, height)| {
+                        RenderedSize {
+                            width: width * device_pixel_ratio,
+                            height: height * device_pixel_ratio,
+                        }
+                    })
+                })
+                .collect(),
+        })
+    }
+}
+
+impl ColorGamut {
+    fn matching() -> Option<Self> {
+        // each gamut contains the previous ones, so the widest matching
+        // one is kept
+        [ColorGamut::Rec2020, ColorGamut::P3, ColorGamut::Srgb]
+            .into_iter()
+            .find(|gamut| {
+                DOM::matches_media(&format!("(color-gamut: {})", gamut.name()))
+            })
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            ColorGamut::Srgb => "srgb",
+            ColorGamut::P3 => "p3",
+            ColorGamut::Rec2020 => "rec2020",
+        }
+    }
+}
+
+impl DynamicRange {
+    fn matching() -> Option<Self> {
+        if DOM::matches_media("(dynamic-range: high)") {
+            Some(DynamicRange::High)
+        } else if DOM::matches_media("(dynamic-range: standard)") {
+            Some(DynamicRange::Standard)
+        } else {
+            None
+        }
+    }
+}
+
+impl ColorScheme {
+    fn matching() -> Option<Self> {
+        if DOM::matches_media("(prefers-color-scheme: dark)") {
+            Some(ColorScheme::Dark)
+        } else if DOM::matches_media("(prefers-color-scheme: light)") {
+            Some(ColorScheme::Light)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+
+    use super::{
+        ColorGamut,
+        RenderedSize,
+        Session,
+    };
+    use crate::{
+        dom::DOM,
+        wasm_sleep_in_ms,
+        WIDE_IMAGE,
+    };
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    fn session_reads_the_viewport() {
+        let session = Session::collect(0).expect("Session to be collected");
+
+        assert_eq!(
+            Some((session.viewport_width, session.viewport_height)),
+            DOM::viewport_size()
+        );
+        assert_eq!(session.device_pixel_ratio, DOM::device_pixel_ratio());
+        // every display covers sRGB
+        assert!(session.color_gamut.is_some());
+    }
+
+    #[wasm_bindgen_test]
+    async fn stimuli_are_measured_in_device_pixels() {
+        let document = DOM::document().expect("Document to exist");
+        let image = document
+            .create_element("img")
+            .expect("Element to be created");
+        image
+            .set_attribute("id", "image_to_compare_0")
+            .expect("Attribute to be set");
+        image
+            .set_attribute("src", WIDE_IMAGE)
+            .expect("Attribute to be set");
+        // a wide picture in a 40x30 box leaves bars above and below it,
+        // and the zoom it is shown with is not part of its size
+        image
+            .set_attribute(
+                "style",
+                "display: block; width: 40px; height: 30px; object-fit: \
+                 contain; transform: scale(2)",
+            )
+            .expect("Attribute to be set");
+        document
+            .body()
+            .expect("Body to exist")
+            .append_child(&image)
+            .expect("Image to be appended");
+        wasm_sleep_in_ms(50).await; // allow image to load
+
+        // there is no second image
+        let session = Session::collect(2).expect("Session to be collected");
+        image.remove();
+
+        let ratio = DOM::device_pixel_ratio();
+        assert_eq!(
+            session.stimuli,
+            vec![
+                Some(RenderedSize {
+                    width: 40.0 * ratio,
+                    height: 20.0 * ratio,
+                }),
+                None,
+            ]
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn media_features_serialize_as_their_css_values() {
+        assert_eq!(
+            serde_json::to_value(ColorGamut::Rec2020).unwrap(),
+            serde_json::json!("rec2020")
+        );
+        assert_eq!(
+            serde_json::to_value(ColorGamut::P3).unwrap(),
+            serde_json::json!(ColorGamut::P3.name())
+        );
+    }
+}
@@ -1,17 +1,14 @@
-#[cfg(test)]
-use std::sync::atomic::{
-    AtomicBool,
-    AtomicUsize,
-    Ordering,
-};
+use std::rc::Rc;
 
 use serde::Deserialize;
 
-use super::Response;
-use crate::dom::{
-    console_error,
-    DOM,
+use super::{
+    config::ApiConfig,
+    read_response,
+    Api,
+    RequestError,
 };
+use crate::dom::DOM;
 
 #[derive(Clone, PartialEq, Default, Deserialize)]
 pub(crate) struct User {
@@ -20,89 +17,52 @@ pub(crate) struct User {
     pub(crate) average_lambda: f64,
 }
 
-pub(crate) async fn get_user() -> Result<User, ()> {
-    #[cfg(test)]
-    if !GET_USER_RETURNS_OK.load(Ordering::SeqCst) {
-        return Err(());
-    }
-
+/// The user whose id is kept in `localStorage`, generating (and storing)
+/// a new one when there is none.
+pub(crate) async fn get_user(api: &Api) -> Result<User, RequestError> {
     let user_id = DOM::local_storage()
         .and_then(|storage| storage.get_item("user_id").unwrap_or(None));
 
     match user_id {
-        Some(id) => get_user_by_id(&id).await,
+        Some(id) => api.get_user_by_id(id).await,
         None => {
-            let user = generate_user().await?;
+            let user = api.generate_user().await?;
             DOM::local_storage()
-                .ok_or(())?
+                .ok_or(RequestError::Storage(
+                    "Unable to fetch localstorage".to_string(),
+                ))?
                 .set_item("user_id", &user.id)
-                .or(Err(()))?;
+                .map_err(|_| {
+                    RequestError::Storage(
+                        "Unable to set `user_id` in localstorage".to_string(),
+                    )
+                })?;
             Ok(user)
         },
     }
 }
 
-pub(crate) async fn get_user_by_id(id: &str) -> Result<User, ()> {
-    #[cfg(test)]
-    if cfg!(test) {
-        return Ok(User {
-            id: id.to_string(),
-            votes: VOTES_TO_DISPLAY.load(Ordering::SeqCst),
-            average_lambda: 0.65,
-        });
-    }
-
-    let user = gloo_net::http::Request::get(&format!("/api/user/{id}"))
-        .send()
-        .await
-        .map_err(|error| console_error!(error.to_string()))?
-        .json::<Response<User, String>>()
-        .await
-        .map_err(|error| console_error!(error.to_string()))?
-        .as_result()
-        .map_err(|error| console_error!(error))?;
-
-    Ok(user)
+pub(super) async fn get_user_by_id(
+    config: Rc<ApiConfig>,
+    id: String,
+) -> Result<User, RequestError> {
+    let response = gloo_net::http::Request::get(
+        &config.api_url(&format!("/api/user/{id}")),
+    )
+    .send()
+    .await?;
+
+    read_response(response).await
 }
 
-pub(crate) async fn generate_user() -> Result<User, ()> {
-    #[cfg(test)]
-    if cfg!(test) {
-        return Ok(User::new());
-    }
-
-    let user = gloo_net::http::Request::post("/api/user")
+pub(super) async fn generate_user(
+    config: Rc<ApiConfig>,
+) -> Result<User, RequestError> {
+    let response = gloo_net::http::Request::post(&config.api_url("/api/user"))
         .send()
-        .await
-        .map_err(|error| console_error!(error.to_string()))?
-        .json::<Response<User, String>>()
-        .await
-        .map_err(|error| console_error!(error.to_string()))?
-        .as_result()
-        .map_err(|error| console_error!(error))?;
-
-    Ok(user)
-}
-
-#[cfg(test)]
-pub(crate) static GET_USER_RETURNS_OK: AtomicBool = AtomicBool::new(true);
+        .await?;
 
-#[cfg(test)]
-pub(crate) static VOTES_TO_DISPLAY: AtomicUsize = AtomicUsize::new(0);
-
-#[cfg(test)]
-impl User {
-    fn new() -> Self {
-        VOTES_TO_DISPLAY.store(0, Ordering::SeqCst);
-        let id: String = (0..16)
-            .map(|_| format!("{:x}", rand::random::<u8>()))
-            .collect();
-        Self {
-            id,
-            votes: 0,
-            average_lambda: 0.0,
-        }
-    }
+    read_response(response).await
 }
 
 #[cfg(test)]
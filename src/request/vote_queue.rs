@@ -0,0 +1,261 @@
+use std::{
+    cell::RefCell,
+    collections::HashSet,
+    sync::atomic::{
+        AtomicBool,
+        Ordering,
+    },
+};
+
+use super::{
+    Api,
+    RequestError,
+    Vote,
+};
+use crate::dom::{
+    console_error,
+    DOM,
+};
+
+const VOTE_QUEUE_KEY: &str = "vote_queue";
+
+static REPLAY_IN_PROGRESS: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    /// Idempotency keys of the votes being posted right now.
+    static SENDING: RefCell<HashSet<String>> = RefCell::new(HashSet::new());
+}
+
+fn is_sending(idempotency_key: &str) -> bool {
+    SENDING.with(|sending| sending.borrow().contains(idempotency_key))
+}
+
+/// Posts a queued vote. [`replay_votes`] leaves it alone until the API
+/// answered, so it is not posted twice at once.
+pub(crate) async fn send_vote(
+    api: &Api,
+    vote: Vote,
+) -> Result<(), RequestError> {
+    let idempotency_key = vote.idempotency_key().to_string();
+    SENDING
+        .with(|sending| sending.borrow_mut().insert(idempotency_key.clone()));
+    let response = api.post_vote(vote).await;
+    SENDING.with(|sending| sending.borrow_mut().remove(&idempotency_key));
+
+    response
+}
+
+/// Votes stored in `localStorage` that the API has not acknowledged yet.
+pub(crate) fn pending_votes() -> Vec<Vote> {
+    DOM::local_storage()
+        .and_then(|storage| storage.get_item(VOTE_QUEUE_KEY).ok()?)
+        .and_then(|queue| serde_json::from_str(&queue).ok())
+        .unwrap_or_default()
+}
+
+fn store_votes(votes: &[Vote]) -> Result<(), RequestError> {
+    let queue = serde_json::to_string(votes)
+        .map_err(|error| RequestError::Json(error.to_string()))?;
+
+    DOM::local_storage()
+        .ok_or(RequestError::Storage(
+            "Unable to fetch localstorage".to_string(),
+        ))?
+        .set_item(VOTE_QUEUE_KEY, &queue)
+        .map_err(|_| {
+            RequestError::Storage(
+                "Unable to set `vote_queue` in localstorage".to_string(),
+            )
+        })
+}
+
+/// Persists `vote` before it is sent, so it survives a failed request or a
+/// closed tab. A vote already queued under the same idempotency key is not
+/// added twice.
+pub(crate) fn enqueue_vote(vote: &Vote) -> Result<(), RequestError> {
+    let mut votes = pending_votes();
+    if votes
+        .iter()
+        .any(|queued| queued.idempotency_key() == vote.idempotency_key())
+    {
+        return Ok(());
+    }
+    votes.push(vote.clone());
+
+    store_votes(&votes)
+}
+
+/// Removes the vote with `idempotency_key` from the queue, once the API has
+/// either accepted or definitively rejected it.
+pub(crate) fn dequeue_vote(idempotency_key: &str) {
+    let mut votes = pending_votes();
+    votes.retain(|vote| vote.idempotency_key() != idempotency_key);
+
+    if let Err(error) = store_votes(&votes) {
+        console_error!(error.to_string());
+    }
+}
+
+/// Sends the queued votes in order, stopping at the first one that may
+/// succeed later, and skipping those already being sent. Votes the API
+/// rejects outright are dropped. Returns the number of votes still
+/// pending.
+pub(crate) async fn replay_votes(api: &Api) -> usize {
+    if REPLAY_IN_PROGRESS.swap(true, Ordering::SeqCst) {
+        return pending_votes().len();
+    }
+
+    for vote in pending_votes() {
+        let idempotency_key = vote.idempotency_key().to_string();
+        if is_sending(&idempotency_key) {
+            continue;
+        }
+        match send_vote(api, vote).await {
+            Ok(_) => dequeue_vote(&idempotency_key),
+            Err(error) if error.is_retryable() => break,
+            Err(error) => {
+                console_error!(error.to_string());
+                dequeue_vote(&idempotency_key);
+            },
+        }
+    }
+
+    REPLAY_IN_PROGRESS.store(false, Ordering::SeqCst);
+    pending_votes().len()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+
+    use super::{
+        dequeue_vote,
+        enqueue_vote,
+        pending_votes,
+        replay_votes,
+        send_vote,
+        VOTE_QUEUE_KEY,
+    };
+    use crate::{
+        dom::DOM,
+        request::{
+            Api,
+            Comparison,
+            FakeClient,
+            RequestError,
+            Vote,
+            VoteValue,
+        },
+    };
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn clear_queue() {
+        DOM::local_storage()
+            .expect("Localstorage to exist")
+            .delete(VOTE_QUEUE_KEY)
+            .expect("Localstorage to be deletable");
+    }
+
+    fn test_vote() -> Vote {
+        Vote::build(Comparison::default())
+            .user("44444444444444444444444444444444".to_string())
+            .vote(VoteValue::Equal)
+    }
+
+    #[wasm_bindgen_test]
+    fn enqueued_votes_are_pending() {
+        clear_queue();
+        let vote = test_vote();
+
+        enqueue_vote(&vote).expect("vote to be enqueued");
+
+        let pending = pending_votes();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].idempotency_key(), vote.idempotency_key());
+    }
+
+    #[wasm_bindgen_test]
+    fn enqueueing_same_vote_twice_does_not_duplicate_it() {
+        clear_queue();
+        let vote = test_vote();
+
+        enqueue_vote(&vote).expect("vote to be enqueued");
+        enqueue_vote(&vote).expect("vote to be enqueued");
+
+        assert_eq!(pending_votes().len(), 1);
+    }
+
+    #[wasm_bindgen_test]
+    fn dequeued_votes_are_no_longer_pending() {
+        clear_queue();
+        let vote = test_vote();
+
+        enqueue_vote(&vote).expect("vote to be enqueued");
+        dequeue_vote(vote.idempotency_key());
+
+        assert!(pending_votes().is_empty());
+    }
+
+    #[wasm_bindgen_test]
+    async fn replayed_votes_are_removed_from_queue() {
+        clear_queue();
+
+        enqueue_vote(&test_vote()).expect("vote to be enqueued");
+        enqueue_vote(&test_vote()).expect("vote to be enqueued");
+
+        let api = FakeClient::default();
+
+        assert_eq!(replay_votes(&Api::from(api.clone())).await, 0);
+        assert!(pending_votes().is_empty());
+        assert_eq!(api.posted_votes().len(), 2);
+    }
+
+    #[wasm_bindgen_test]
+    async fn votes_being_sent_are_not_replayed() {
+        clear_queue();
+        let vote = test_vote();
+
+        enqueue_vote(&vote).expect("vote to be enqueued");
+        let api = FakeClient::default().vote_latency(Duration::from_millis(50));
+        let client = Api::from(api.clone());
+
+        let (sent, pending) =
+            futures::join!(send_vote(&client, vote), replay_votes(&client));
+
+        assert_eq!(sent, Ok(()));
+        // still queued, for the sender to dequeue
+        assert_eq!(pending, 1);
+        assert_eq!(api.posted_votes().len(), 1);
+    }
+
+    #[wasm_bindgen_test]
+    async fn votes_failing_with_retryable_error_stay_queued() {
+        clear_queue();
+
+        enqueue_vote(&test_vote()).expect("vote to be enqueued");
+        let api = FakeClient::default()
+            .vote_error(RequestError::Network("offline".to_string()));
+
+        assert_eq!(replay_votes(&Api::from(api)).await, 1);
+        assert_eq!(pending_votes().len(), 1);
+    }
+
+    #[wasm_bindgen_test]
+    async fn votes_rejected_by_api_are_dropped() {
+        clear_queue();
+
+        enqueue_vote(&test_vote()).expect("vote to be enqueued");
+        let api = FakeClient::default().vote_error(RequestError::Server {
+            code: None,
+            error: "Invalid vote".to_string(),
+        });
+
+        assert_eq!(replay_votes(&Api::from(api)).await, 0);
+        assert!(pending_votes().is_empty());
+    }
+}
@@ -7,7 +7,10 @@ use yew_router::{
     Routable,
 };
 
-use crate::pages;
+use crate::{
+    pages,
+    DEFAULT_PAGE,
+};
 
 #[derive(Clone, Routable, PartialEq)]
 pub(crate) enum Route {
@@ -15,8 +18,32 @@ pub(crate) enum Route {
     Root,
     #[at("/experiments/compare")]
     ExperimentCompare,
+    #[at("/experiments/compare/:dirname")]
+    ExperimentCompareDirname { dirname: String },
     #[at("/experiments/same_or_different")]
     ExperimentEqualOrDifferent,
+    #[at("/experiments/same_or_different/:dirname")]
+    ExperimentEqualOrDifferentDirname { dirname: String },
+    #[at("/experiments/rating")]
+    ExperimentRating,
+    #[at("/experiments/rating/:dirname")]
+    ExperimentRatingDirname { dirname: String },
+    #[at("/experiments/ranking")]
+    ExperimentRanking,
+    #[at("/experiments/ranking/:dirname")]
+    ExperimentRankingDirname { dirname: String },
+    #[at("/experiments/abx")]
+    ExperimentAbx,
+    #[at("/experiments/abx/:dirname")]
+    ExperimentAbxDirname { dirname: String },
+    #[at("/experiments/best_worst")]
+    ExperimentBestWorst,
+    #[at("/experiments/best_worst/:dirname")]
+    ExperimentBestWorstDirname { dirname: String },
+    #[at("/experiments/artifacts")]
+    ExperimentArtifacts,
+    #[at("/experiments/artifacts/:dirname")]
+    ExperimentArtifactsDirname { dirname: String },
     #[not_found]
     #[at("/404")]
     NotFound,
@@ -25,16 +52,194 @@ pub(crate) enum Route {
 pub(crate) fn switch(routes: Route) -> Html {
     match routes {
         Route::Root => {
-            html! { <pages::ExperimentEqualOrDifferent /> }
+            html! {
+                <pages::ExperimentEqualOrDifferent dirname={DEFAULT_PAGE} />
+            }
         },
         Route::ExperimentCompare => {
-            html! { <pages::ImagesToCompare /> }
+            html! {
+                <Redirect<Route>
+                    to={Route::ExperimentCompareDirname {
+                        dirname: DEFAULT_PAGE.to_string(),
+                    }}
+                />
+            }
+        },
+        Route::ExperimentCompareDirname { dirname } => {
+            html! { <pages::ImagesToCompare dirname={dirname} /> }
         },
         Route::ExperimentEqualOrDifferent => {
-            html! { <pages::ExperimentEqualOrDifferent /> }
+            html! {
+                <Redirect<Route>
+                    to={Route::ExperimentEqualOrDifferentDirname {
+                        dirname: DEFAULT_PAGE.to_string(),
+                    }}
+                />
+            }
+        },
+        Route::ExperimentEqualOrDifferentDirname { dirname } => {
+            html! {
+                <pages::ExperimentEqualOrDifferent dirname={dirname} />
+            }
+        },
+        Route::ExperimentRating => {
+            html! {
+                <Redirect<Route>
+                    to={Route::ExperimentRatingDirname {
+                        dirname: DEFAULT_PAGE.to_string(),
+                    }}
+                />
+            }
+        },
+        Route::ExperimentRatingDirname { dirname } => {
+            html! { <pages::ExperimentRating dirname={dirname} /> }
+        },
+        Route::ExperimentRanking => {
+            html! {
+                <Redirect<Route>
+                    to={Route::ExperimentRankingDirname {
+                        dirname: DEFAULT_PAGE.to_string(),
+                    }}
+                />
+            }
+        },
+        Route::ExperimentRankingDirname { dirname } => {
+            html! { <pages::ExperimentRanking dirname={dirname} /> }
+        },
+        Route::ExperimentAbx => {
+            html! {
+                <Redirect<Route>
+                    to={Route::ExperimentAbxDirname {
+                        dirname: DEFAULT_PAGE.to_string(),
+                    }}
+                />
+            }
+        },
+        Route::ExperimentAbxDirname { dirname } => {
+            html! { <pages::ExperimentAbx dirname={dirname} /> }
+        },
+        Route::ExperimentBestWorst => {
+            html! {
+                <Redirect<Route>
+                    to={Route::ExperimentBestWorstDirname {
+                        dirname: DEFAULT_PAGE.to_string(),
+                    }}
+                />
+            }
+        },
+        Route::ExperimentBestWorstDirname { dirname } => {
+            html! { <pages::ExperimentBestWorst dirname={dirname} /> }
+        },
+        Route::ExperimentArtifacts => {
+            html! {
+                <Redirect<Route>
+                    to={Route::ExperimentArtifactsDirname {
+                        dirname: DEFAULT_PAGE.to_string(),
+                    }}
+                />
+            }
+        },
+        Route::ExperimentArtifactsDirname { dirname } => {
+            html! { <pages::ExperimentArtifacts dirname={dirname} /> }
         },
         Route::NotFound => {
             html! { <Redirect<Route> to={Route::Root} /> }
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+    use yew_router::Routable;
+
+    use super::Route;
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    fn compare_route_takes_dirname_from_path() {
+        let route = Route::recognize("/experiments/compare/set_0");
+
+        assert!(
+            route
+                == Some(Route::ExperimentCompareDirname {
+                    dirname: "set_0".to_string(),
+                })
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn same_or_different_route_takes_dirname_from_path() {
+        let route = Route::recognize("/experiments/same_or_different/set_0");
+
+        assert!(
+            route
+                == Some(Route::ExperimentEqualOrDifferentDirname {
+                    dirname: "set_0".to_string(),
+                })
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn rating_route_takes_dirname_from_path() {
+        let route = Route::recognize("/experiments/rating/set_0");
+
+        assert!(
+            route
+                == Some(Route::ExperimentRatingDirname {
+                    dirname: "set_0".to_string(),
+                })
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn ranking_route_takes_dirname_from_path() {
+        let route = Route::recognize("/experiments/ranking/set_0");
+
+        assert!(
+            route
+                == Some(Route::ExperimentRankingDirname {
+                    dirname: "set_0".to_string(),
+                })
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn abx_route_takes_dirname_from_path() {
+        let route = Route::recognize("/experiments/abx/set_0");
+
+        assert!(
+            route
+                == Some(Route::ExperimentAbxDirname {
+                    dirname: "set_0".to_string(),
+                })
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn best_worst_route_takes_dirname_from_path() {
+        let route = Route::recognize("/experiments/best_worst/set_0");
+
+        assert!(
+            route
+                == Some(Route::ExperimentBestWorstDirname {
+                    dirname: "set_0".to_string(),
+                })
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn artifacts_route_takes_dirname_from_path() {
+        let route = Route::recognize("/experiments/artifacts/set_0");
+
+        assert!(
+            route
+                == Some(Route::ExperimentArtifactsDirname {
+                    dirname: "set_0".to_string(),
+                })
+        );
+    }
+}
@@ -40,7 +40,7 @@ pub(crate) fn button(props: &ButtonProps) -> Html {
                 props.class.clone(),
             ]}
             onclick={onclick}
-            disabled={props.disabled.clone()}
+            disabled={props.disabled}
         >
             { for props.children.iter() }
         </button>
@@ -0,0 +1,35 @@
+use std::time::Duration;
+
+use yew::{
+    classes,
+    function_component,
+    html,
+    Html,
+    Properties,
+};
+
+#[derive(Properties, PartialEq)]
+pub(crate) struct DwellProgressProps {
+    pub(crate) duration: Duration,
+}
+
+/// Thin bar filling up over the minimum viewing time. Give it a `key` to
+/// restart it.
+#[function_component(DwellProgress)]
+pub(crate) fn dwell_progress(props: &DwellProgressProps) -> Html {
+    html! {
+        <div
+            id="dwell_progress"
+            class={classes!["h-0.5", "w-full", "bg-gray-300"]}
+            role="progressbar"
+        >
+            <div
+                class={classes!["h-full", "bg-gray-600", "animate-dwell"]}
+                style={format!(
+                    "animation-duration: {}ms;",
+                    props.duration.as_millis()
+                )}
+            />
+        </div>
+    }
+}
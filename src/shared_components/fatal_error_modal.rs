@@ -11,13 +11,22 @@ use yew::{
 
 use crate::{
     pages::markdown_to_yew_html,
-    shared_components::Modal,
+    request::RequestError,
+    shared_components::{
+        Button,
+        Modal,
+    },
     Language,
 };
 
 #[derive(Properties, PartialEq)]
 pub(crate) struct FatalErrorModalProps {
     pub(crate) onclose: Callback<()>,
+    #[prop_or_default]
+    pub(crate) error: Option<RequestError>,
+    /// Shows a retry button when set.
+    #[prop_or_default]
+    pub(crate) onretry: Option<Callback<()>>,
 }
 
 #[function_component(FatalErrorModal)]
@@ -27,10 +36,17 @@ pub(crate) fn fatal_error_modal(props: &FatalErrorModalProps) -> Html {
         None => Language::default(),
     };
 
-    let fatal_error_message = language.load_file("fatal_error_message.md");
+    let message_file = match &props.error {
+        Some(error) => error.message_file(),
+        None => "fatal_error_message.md",
+    };
+    let fatal_error_message = language.load_file(message_file);
     let fatal_error_message =
         markdown_to_yew_html(fatal_error_message.unwrap_or(""));
 
+    let retry_button = language.load_file("retry_button.md");
+    let retry_button = markdown_to_yew_html(retry_button.unwrap_or(""));
+
     html! {
         <Modal
             id={"fatal_error_modal"}
@@ -49,6 +65,27 @@ pub(crate) fn fatal_error_modal(props: &FatalErrorModalProps) -> Html {
             >
                 { fatal_error_message }
             </section>
+            if let Some(onretry) = props.onretry.clone() {
+                <section
+                    id={"fatal_error_buttons"}
+                    class={classes![
+                        "pb-8",
+                        "self-center",
+                    ]}
+                >
+                    <Button
+                        id={"fatal_error_retry_button"}
+                        class={classes![
+                            "text-gray-600",
+                            "border-2",
+                            "border-gray-600",
+                        ]}
+                        onclick={onretry}
+                    >
+                        { retry_button }
+                    </Button>
+                </section>
+            }
         </Modal>
     }
 }
@@ -64,6 +101,7 @@ mod tests {
     use yew::{
         function_component,
         html,
+        Callback,
         Html,
     };
 
@@ -72,6 +110,7 @@ mod tests {
         dom::DOM,
         markdown_to_decoded_html,
         render_yew_component,
+        request::RequestError,
         wasm_sleep_in_ms,
         Language,
         AVAILABLE_LANGUAGES,
@@ -88,6 +127,19 @@ mod tests {
         }
     }
 
+    #[function_component(TestNetworkErrorModal)]
+    fn test_network_error_modal() -> Html {
+        html! {
+            <div>
+                <FatalErrorModal
+                    onclose={|_| ()}
+                    onretry={Callback::from(|_| ())}
+                    error={RequestError::Network("offline".to_string())}
+                />
+            </div>
+        }
+    }
+
     #[wasm_bindgen_test]
     fn fatal_error_message_markdown_exists() {
         // add 1 to len to run even if no languages are available
@@ -120,4 +172,47 @@ mod tests {
             assert_eq!(text.inner_html(), expected);
         }
     }
+
+    #[wasm_bindgen_test]
+    fn error_message_markdown_exists() {
+        // add 1 to len to run even if no languages are available
+        for language_index in 0..AVAILABLE_LANGUAGES.len() + 1 {
+            let language = Language {
+                index: language_index,
+            };
+
+            assert!(language.load_file("network_error_message.md").is_some());
+            assert!(language.load_file("storage_error_message.md").is_some());
+            assert!(language.load_file("retry_button.md").is_some());
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn retry_button_is_hidden_by_default() {
+        render_yew_component!(TestFatalErrorModal);
+        wasm_sleep_in_ms(50).await;
+
+        assert!(DOM::get_button_by_id("fatal_error_retry_button").is_none());
+    }
+
+    #[wasm_bindgen_test]
+    async fn network_error_shows_targeted_message_and_retry_button() {
+        // add 1 to len to run even if no languages are available
+        for language_index in 0..AVAILABLE_LANGUAGES.len() + 1 {
+            DEFAULT_LANGUAGE.store(language_index, Ordering::SeqCst);
+
+            render_yew_component!(TestNetworkErrorModal);
+            wasm_sleep_in_ms(50).await;
+
+            let language = Language::default();
+            let expected = language.load_file("network_error_message.md");
+            let expected = markdown_to_decoded_html(expected.unwrap_or(""));
+
+            let text = DOM::get_element_by_id("fatal_error_message")
+                .expect("Element #fatal_error_message to exist");
+
+            assert_eq!(text.inner_html(), expected);
+            assert!(DOM::get_button_by_id("fatal_error_retry_button").is_some());
+        }
+    }
 }
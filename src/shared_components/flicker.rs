@@ -0,0 +1,195 @@
+use std::{
+    cell::Cell,
+    rc::Rc,
+    time::Duration,
+};
+
+use gloo_events::EventListener;
+use wasm_bindgen::JsCast;
+use yew::{
+    classes,
+    function_component,
+    html,
+    platform::time::sleep,
+    use_effect_with,
+    use_state_eq,
+    Callback,
+    Html,
+    Properties,
+};
+
+use super::ImageLabel;
+use crate::dom::DOM;
+
+/// Key switching to the next image.
+const FLICKER_KEY: &str = " ";
+
+/// Elements the space bar already does something in, left to it.
+const KEY_OWNERS: &str =
+    "button, input, select, textarea, a[href], [role=\"dialog\"]";
+
+#[derive(Properties, PartialEq)]
+pub(crate) struct FlickerProps {
+    pub(crate) images: Vec<String>,
+    /// How long each image is shown before switching to the next one;
+    /// `None` switches only on a key press or tap.
+    #[prop_or_default]
+    pub(crate) interval: Option<Duration>,
+}
+
+/// Whether the key press is meant for the element it happened in, e.g. a
+/// focused button or an open modal.
+fn is_owned_by_element(target: Option<web_sys::EventTarget>) -> bool {
+    target
+        .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+        .and_then(|element| element.closest(KEY_OWNERS).ok().flatten())
+        .is_some()
+}
+
+/// Shows the images one at a time in the same place, labelled A, B, ...,
+/// switching at the interval, when the space bar is pressed or when the
+/// images are tapped.
+#[function_component(Flicker)]
+pub(crate) fn flicker(props: &FlickerProps) -> Html {
+    let shown = use_state_eq(|| 0_usize);
+    let count = props.images.len().max(1);
+
+    {
+        let shown = shown.clone();
+        use_effect_with(props.images.clone(), move |_| shown.set(0));
+    }
+
+    // restarted by every switch, so a key press gives the image a full
+    // interval on screen
+    {
+        let shown = shown.clone();
+        use_effect_with(
+            (*shown, props.interval, props.images.clone()),
+            move |(current, interval, _)| {
+                let cancelled = Rc::new(Cell::new(false));
+                if let Some(interval) = *interval {
+                    let cancelled = cancelled.clone();
+                    let next = (*current + 1) % count;
+                    wasm_bindgen_futures::spawn_local(async move {
+                        sleep(interval).await;
+                        if !cancelled.get() {
+                            shown.set(next);
+                        }
+                    });
+                }
+                move || cancelled.set(true)
+            },
+        );
+    }
+
+    {
+        let shown = shown.clone();
+        use_effect_with(*shown, move |current| {
+            let current = *current;
+            let listener = DOM::window().map(|window| {
+                EventListener::new(&window, "keydown", move |event| {
+                    let Some(event) = event.dyn_ref::<web_sys::KeyboardEvent>()
+                    else {
+                        return;
+                    };
+                    if event.key() != FLICKER_KEY
+                        || event.repeat()
+                        || is_owned_by_element(event.target())
+                    {
+                        return;
+                    }
+                    // keep the space bar from scrolling the page
+                    event.prevent_default();
+                    shown.set((current + 1) % count);
+                })
+            });
+            move || drop(listener)
+        });
+    }
+
+    let on_tap = {
+        let shown = shown.clone();
+        Callback::from(move |_| shown.set((*shown + 1) % count))
+    };
+
+    let images = props
+        .images
+        .iter()
+        .enumerate()
+        .map(|(index, image)| {
+            html! {
+                <img
+                    id={format!("image_to_compare_{index}")}
+                    class={classes![
+                        "absolute",
+                        "inset-0",
+                        "h-full",
+                        "w-full",
+                        "object-contain",
+                        (index != *shown).then_some("invisible"),
+                    ]}
+                    src={image.clone()}
+                    alt=""
+                    draggable="false"
+                />
+            }
+        })
+        .collect::<Html>();
+
+    html! {
+        <div
+            id="flicker"
+            class={classes![
+                "relative",
+                "h-1/2",
+                "md:h-5/6",
+                "aspect-square",
+                "overflow-hidden",
+                "select-none",
+            ]}
+            onclick={on_tap}
+        >
+            { images }
+            <ImageLabel
+                index={*shown}
+                class={classes!["absolute", "top-2", "left-2"]}
+            />
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+
+    use super::is_owned_by_element;
+    use crate::dom::DOM;
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    fn space_in_buttons_and_modals_is_left_to_them() {
+        let document = DOM::document().expect("Document to exist");
+        let modal = document
+            .create_element("section")
+            .expect("Element to be created");
+        modal
+            .set_attribute("role", "dialog")
+            .expect("Attribute to be set");
+        let text = document.create_element("p").expect("Element to be created");
+        modal.append_child(&text).expect("Text to be appended");
+        let button = document
+            .create_element("button")
+            .expect("Element to be created");
+
+        assert!(is_owned_by_element(Some(button.unchecked_into())));
+        assert!(is_owned_by_element(Some(text.unchecked_into())));
+        assert!(!is_owned_by_element(Some(
+            document.body().expect("Body to exist").unchecked_into()
+        )));
+        assert!(!is_owned_by_element(None));
+    }
+}
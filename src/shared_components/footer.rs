@@ -173,6 +173,7 @@ mod tests {
         }
     }
 
+    #[allow(clippy::expect_fun_call)]
     #[wasm_bindgen_test]
     async fn language_menu_buttons_change_current_language() {
         render_yew_component!(Footer);
@@ -0,0 +1,119 @@
+use yew::{
+    classes,
+    function_component,
+    html,
+    Callback,
+    Children,
+    Html,
+    Properties,
+};
+
+use super::{
+    Button,
+    Loading,
+    ReferenceImage,
+};
+
+#[derive(Properties, PartialEq)]
+pub(crate) struct ImageGridProps {
+    pub(crate) loading: bool,
+    pub(crate) images: Vec<String>,
+    /// Shown in the first cell, apart from the images and not clickable.
+    #[prop_or_default]
+    pub(crate) reference: Option<String>,
+    /// Called with the index of the image clicked.
+    pub(crate) onclick: Callback<usize>,
+    /// Shown over each image, in the order of `images`, e.g. its rank.
+    #[prop_or_default]
+    pub(crate) badges: Vec<Html>,
+    /// Shown over the whole grid, e.g. an exposure mask.
+    #[prop_or_default]
+    pub(crate) children: Children,
+}
+
+/// Images laid out in a grid, 2 columns on small screens and up to 4 on
+/// larger ones, each image a button, after the reference if any.
+#[function_component(ImageGrid)]
+pub(crate) fn image_grid(props: &ImageGridProps) -> Html {
+    let reference = props.reference.clone().filter(|_| !props.loading);
+    let cells = props.images.len() + usize::from(reference.is_some());
+    let grid_columns = match cells {
+        0..=2 => classes!["md:grid-cols-2"],
+        3 | 5 | 6 => classes!["md:grid-cols-3"],
+        _ => classes!["md:grid-cols-4"],
+    };
+
+    let image_list = props
+        .images
+        .iter()
+        .enumerate()
+        .map(|(index, image)| {
+            if props.loading {
+                html! {
+                    <Button
+                        id={format!("loading_status_button_{index}")}
+                        class={classes!["aspect-square"]}
+                        disabled=true
+                    >
+                        <Loading />
+                    </Button>
+                }
+            } else {
+                let onclick = {
+                    let onclick = props.onclick.clone();
+                    Callback::from(move |_| onclick.emit(index))
+                };
+
+                html! {
+                    <Button
+                        id={format!("image_to_compare_button_{index}")}
+                        class={classes![
+                            "relative",
+                            "aspect-square",
+                            "flex",
+                            "items-center",
+                            "justify-center",
+                        ]}
+                        onclick={onclick}
+                    >
+                        <img
+                            id={format!("image_to_compare_{index}")}
+                            class={classes![
+                                "max-h-full",
+                                "max-w-full",
+                                "object-contain",
+                            ]}
+                            src={image.clone()}
+                            alt=""
+                        />
+                        { props.badges.get(index).cloned().unwrap_or_default() }
+                    </Button>
+                }
+            }
+        })
+        .collect::<Html>();
+
+    html! {
+        <section
+            id="images_list"
+            class={classes![
+                "relative",
+                "flex-1",
+                "overflow-auto",
+                "grid",
+                "grid-cols-2",
+                grid_columns,
+                "content-center",
+                "gap-2",
+                "md:gap-4",
+                "p-2",
+            ]}
+        >
+            if let Some(reference) = reference {
+                <ReferenceImage image={reference} class={classes!["w-full"]} />
+            }
+            { image_list }
+            { for props.children.iter() }
+        </section>
+    }
+}
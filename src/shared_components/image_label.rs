@@ -0,0 +1,72 @@
+use yew::{
+    classes,
+    function_component,
+    html,
+    Classes,
+    Html,
+    Properties,
+};
+
+/// Letter naming the image at `index` ("A", "B", ... "Z"), for
+/// presentations where the images are not told apart by their place on
+/// screen. Past the alphabet, images are numbered from 27.
+pub(crate) fn image_label(index: usize) -> String {
+    match u8::try_from(index) {
+        Ok(index) if index < 26 => char::from(b'A' + index).to_string(),
+        _ => (index + 1).to_string(),
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub(crate) struct ImageLabelProps {
+    pub(crate) index: usize,
+    #[prop_or_default]
+    pub(crate) class: Classes,
+}
+
+#[function_component(ImageLabel)]
+pub(crate) fn image_label_badge(props: &ImageLabelProps) -> Html {
+    html! {
+        <span
+            id={format!("image_label_{}", props.index)}
+            class={classes![
+                "w-10",
+                "h-10",
+                "rounded-full",
+                "bg-gray-800",
+                "text-gray-100",
+                "flex",
+                "items-center",
+                "justify-center",
+                "pointer-events-none",
+                props.class.clone(),
+            ]}
+        >
+            { image_label(props.index) }
+        </span>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::{
+        wasm_bindgen_test,
+        wasm_bindgen_test_configure,
+    };
+
+    use super::image_label;
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    fn images_are_labelled_with_letters() {
+        assert_eq!(image_label(0), "A");
+        assert_eq!(image_label(1), "B");
+        assert_eq!(image_label(25), "Z");
+    }
+
+    #[wasm_bindgen_test]
+    fn images_past_the_alphabet_are_numbered() {
+        assert_eq!(image_label(26), "27");
+        assert_eq!(image_label(300), "301");
+    }
+}
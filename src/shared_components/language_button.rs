@@ -188,6 +188,7 @@ mod tests {
         }
     }
 
+    #[allow(clippy::expect_fun_call)]
     #[wasm_bindgen_test]
     async fn changing_language_changes_lang_attribute_on_document() {
         render_yew_component!(LanguageButton);
@@ -0,0 +1,101 @@
+use wasm_bindgen::JsCast;
+use yew::{
+    classes,
+    function_component,
+    html,
+    Html,
+    MouseEvent,
+    Properties,
+};
+
+/// Size of the frame relative to the loupe, whose element is `w-1/3` and
+/// `h-1/3` of it.
+const FRAME_IN_LOUPES: f64 = 3.0;
+
+/// Position of the event in the frame it was listened on, normalized to
+/// its size, along with that size.
+pub(crate) fn frame_position(
+    event: &MouseEvent,
+) -> Option<((f64, f64), (f64, f64))> {
+    let frame = event
+        .current_target()?
+        .dyn_into::<web_sys::Element>()
+        .ok()?;
+    let rect = frame.get_bounding_client_rect();
+    if rect.width() <= 0.0 || rect.height() <= 0.0 {
+        return None;
+    }
+    let x = (f64::from(event.client_x()) - rect.left()) / rect.width();
+    let y = (f64::from(event.client_y()) - rect.top()) / rect.height();
+
+    Some(((x, y), (rect.width(), rect.height())))
+}
+
+/// CSS of the image in a loupe centered on `(x, y)` (in frame
+/// coordinates), magnifying by `magnification` an image zoomed by `zoom`
+/// whose point `(left, top)` is at the top-left corner of the frame.
+pub(crate) fn loupe_style(
+    zoom: f64,
+    (left, top): (f64, f64),
+    (x, y): (f64, f64),
+    magnification: f64,
+) -> String {
+    // size of the image relative to the loupe
+    let scale = zoom * magnification * FRAME_IN_LOUPES;
+    let (image_x, image_y) = (left + x / zoom, top + y / zoom);
+
+    format!(
+        "width: {0}%; height: {0}%; left: {1}%; top: {2}%; image-rendering: \
+         pixelated;",
+        scale * 100.0,
+        (0.5 - image_x * scale) * 100.0,
+        (0.5 - image_y * scale) * 100.0,
+    )
+}
+
+#[derive(Properties, PartialEq)]
+pub(crate) struct LoupeProps {
+    pub(crate) id: String,
+    pub(crate) image: String,
+    /// Center of the loupe, in frame coordinates.
+    pub(crate) x: f64,
+    pub(crate) y: f64,
+    /// CSS of the image in the loupe, see [`loupe_style`].
+    pub(crate) image_style: String,
+}
+
+/// Round magnifier over the frame of an image, which must be positioned.
+#[function_component(Loupe)]
+pub(crate) fn loupe(props: &LoupeProps) -> Html {
+    html! {
+        <div
+            id={props.id.clone()}
+            class={classes![
+                "absolute",
+                "w-1/3",
+                "h-1/3",
+                "-translate-x-1/2",
+                "-translate-y-1/2",
+                "rounded-full",
+                "overflow-hidden",
+                "border-2",
+                "border-gray-100",
+                "bg-gray-800",
+                "pointer-events-none",
+            ]}
+            style={format!(
+                "left: {}%; top: {}%;",
+                props.x * 100.0,
+                props.y * 100.0,
+            )}
+        >
+            <img
+                class={classes!["absolute", "max-w-none", "object-contain"]}
+                style={props.image_style.clone()}
+                src={props.image.clone()}
+                alt=""
+                draggable="false"
+            />
+        </div>
+    }
+}
@@ -1,13 +1,34 @@
 pub(crate) mod button;
+pub(crate) mod dwell;
 pub(crate) mod fatal_error_modal;
+pub(crate) mod flicker;
 pub(crate) mod footer;
+pub(crate) mod image_grid;
+pub(crate) mod image_label;
 pub(crate) mod language_button;
 pub(crate) mod loading;
+pub(crate) mod loupe;
 pub(crate) mod modal;
+pub(crate) mod reference_image;
+pub(crate) mod wipe;
 
 pub(crate) use button::Button;
+pub(crate) use dwell::DwellProgress;
 pub(crate) use fatal_error_modal::FatalErrorModal;
+pub(crate) use flicker::Flicker;
 pub(crate) use footer::Footer;
+pub(crate) use image_grid::ImageGrid;
+pub(crate) use image_label::{
+    image_label,
+    ImageLabel,
+};
 pub(crate) use language_button::LanguageButton;
 pub(crate) use loading::Loading;
+pub(crate) use loupe::{
+    frame_position,
+    loupe_style,
+    Loupe,
+};
 pub(crate) use modal::Modal;
+pub(crate) use reference_image::ReferenceImage;
+pub(crate) use wipe::Wipe;
@@ -61,6 +61,8 @@ pub(crate) fn Modal(props: &ModalProps) -> Html {
             >
                 <section
                     id="modal_content"
+                    role="dialog"
+                    aria-modal="true"
                     class={classes![
                         "mt-8",
                         "lg:mt-8",
@@ -113,7 +115,7 @@ pub(crate) fn Modal(props: &ModalProps) -> Html {
                 </section>
             </section>
         },
-        modal_host.into(),
+        modal_host,
     )
 }
 
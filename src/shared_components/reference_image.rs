@@ -0,0 +1,79 @@
+use yew::{
+    classes,
+    function_component,
+    html,
+    use_context,
+    Classes,
+    Html,
+    Properties,
+    UseReducerHandle,
+};
+
+use crate::{
+    pages::markdown_to_yew_html,
+    Language,
+};
+
+#[derive(Properties, PartialEq)]
+pub(crate) struct ReferenceImageProps {
+    pub(crate) image: String,
+    /// Size of the frame, which is square: half the height on small
+    /// screens and most of it on larger ones by default.
+    #[prop_or_else(|| classes!["h-1/2", "md:h-5/6"])]
+    pub(crate) class: Classes,
+    /// CSS of the image, e.g. to zoom it with the images compared to it.
+    #[prop_or_default]
+    pub(crate) style: String,
+}
+
+/// Pristine image of a double-stimulus trial, framed and labelled apart
+/// from the images being judged, and not clickable.
+#[function_component(ReferenceImage)]
+pub(crate) fn reference_image(props: &ReferenceImageProps) -> Html {
+    let language = match use_context::<UseReducerHandle<Language>>() {
+        Some(ctx) => (*ctx).clone(),
+        None => Language::default(),
+    };
+
+    let reference_label = language.load_file("reference_label.md");
+    let reference_label = markdown_to_yew_html(reference_label.unwrap_or(""));
+
+    html! {
+        <figure
+            id="reference"
+            class={classes![
+                "relative",
+                "aspect-square",
+                "overflow-hidden",
+                "border-4",
+                "border-dashed",
+                "border-gray-400",
+                "rounded-xl",
+                props.class.clone(),
+            ]}
+        >
+            <img
+                id="reference_image"
+                class={classes!["h-full", "w-full", "object-contain"]}
+                style={props.style.clone()}
+                src={props.image.clone()}
+                alt=""
+                draggable="false"
+            />
+            <figcaption
+                id="reference_label"
+                class={classes![
+                    "absolute",
+                    "top-2",
+                    "left-2",
+                    "px-2",
+                    "rounded-md",
+                    "bg-gray-800",
+                    "text-gray-100",
+                ]}
+            >
+                { reference_label }
+            </figcaption>
+        </figure>
+    }
+}
@@ -0,0 +1,176 @@
+use wasm_bindgen::JsCast;
+use yew::{
+    classes,
+    function_component,
+    html,
+    use_effect_with,
+    use_mut_ref,
+    use_state_eq,
+    Callback,
+    Html,
+    KeyboardEvent,
+    MouseEvent,
+    PointerEvent,
+    Properties,
+};
+
+use super::ImageLabel;
+
+/// How far, in percent of the width, the arrow keys move the divider.
+const KEYBOARD_STEP: f64 = 5.0;
+
+#[derive(Properties, PartialEq)]
+pub(crate) struct WipeProps {
+    /// The first two are shown, the first one left of the divider.
+    pub(crate) images: Vec<String>,
+}
+
+/// Position of the event across the element it was listened on, in percent
+/// of its width.
+fn divider_position(event: &MouseEvent) -> Option<f64> {
+    let frame = event
+        .current_target()?
+        .dyn_into::<web_sys::Element>()
+        .ok()?;
+    let rect = frame.get_bounding_client_rect();
+    if rect.width() <= 0.0 {
+        return None;
+    }
+    let x = (f64::from(event.client_x()) - rect.left()) / rect.width();
+
+    Some((x * 100.0).clamp(0.0, 100.0))
+}
+
+/// Overlays two images, with a divider dragged (or moved with the arrow
+/// keys) to reveal one or the other.
+#[function_component(Wipe)]
+pub(crate) fn wipe(props: &WipeProps) -> Html {
+    let position = use_state_eq(|| 50.0_f64);
+    let dragging = use_mut_ref(|| false);
+
+    {
+        let position = position.clone();
+        use_effect_with(props.images.clone(), move |_| position.set(50.0));
+    }
+
+    let on_pointer_down = {
+        let position = position.clone();
+        let dragging = dragging.clone();
+        Callback::from(move |event: PointerEvent| {
+            *dragging.borrow_mut() = true;
+            // keep following the pointer when it leaves the images
+            if let Some(frame) = event
+                .current_target()
+                .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+            {
+                let _ = frame.set_pointer_capture(event.pointer_id());
+            }
+            if let Some(new_position) = divider_position(&event) {
+                position.set(new_position);
+            }
+        })
+    };
+
+    let on_pointer_move = {
+        let position = position.clone();
+        let dragging = dragging.clone();
+        Callback::from(move |event: PointerEvent| {
+            if !*dragging.borrow() {
+                return;
+            }
+            if let Some(new_position) = divider_position(&event) {
+                position.set(new_position);
+            }
+        })
+    };
+
+    let on_pointer_up = {
+        let dragging = dragging.clone();
+        Callback::from(move |_: PointerEvent| *dragging.borrow_mut() = false)
+    };
+
+    let on_key_down = {
+        let position = position.clone();
+        Callback::from(move |event: KeyboardEvent| {
+            let step = match event.key().as_str() {
+                "ArrowLeft" => -KEYBOARD_STEP,
+                "ArrowRight" => KEYBOARD_STEP,
+                _ => return,
+            };
+            event.prevent_default();
+            position.set((*position + step).clamp(0.0, 100.0));
+        })
+    };
+
+    let image = |index: usize| {
+        let style = match index {
+            0 => String::new(),
+            _ => format!("clip-path: inset(0 0 0 {}%);", *position),
+        };
+        html! {
+            <img
+                id={format!("image_to_compare_{index}")}
+                class={classes![
+                    "absolute",
+                    "inset-0",
+                    "h-full",
+                    "w-full",
+                    "object-contain",
+                ]}
+                style={style}
+                src={props.images.get(index).cloned().unwrap_or_default()}
+                alt=""
+                draggable="false"
+            />
+        }
+    };
+
+    html! {
+        <div
+            id="wipe"
+            class={classes![
+                "relative",
+                "h-1/2",
+                "md:h-5/6",
+                "aspect-square",
+                "overflow-hidden",
+                "touch-none",
+                "select-none",
+                "cursor-ew-resize",
+            ]}
+            onpointerdown={on_pointer_down}
+            onpointermove={on_pointer_move}
+            onpointerup={on_pointer_up.clone()}
+            onpointercancel={on_pointer_up}
+        >
+            { image(0) }
+            { image(1) }
+            <div
+                id="wipe_divider"
+                role="slider"
+                tabindex="0"
+                aria-valuemin="0"
+                aria-valuemax="100"
+                aria-valuenow={format!("{:.0}", *position)}
+                class={classes![
+                    "absolute",
+                    "inset-y-0",
+                    "w-1",
+                    "-ml-0.5",
+                    "bg-gray-100",
+                    "drop-shadow",
+                ]}
+                style={format!("left: {}%;", *position)}
+                onkeydown={on_key_down}
+            />
+            <ImageLabel
+                index={0}
+                class={classes!["absolute", "top-2", "left-2"]}
+            />
+            <ImageLabel
+                index={1}
+                class={classes!["absolute", "top-2", "right-2"]}
+            />
+        </div>
+    }
+}
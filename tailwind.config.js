@@ -8,6 +8,16 @@ module.exports = {
       fontFamily: {
         'hyperlegible': ['Atkinson Hyperlegible', 'sans-serif']
       },
+      keyframes: {
+        dwell: {
+          from: { width: '0%' },
+          to: { width: '100%' },
+        },
+      },
+      // the duration is set inline, from the experiment
+      animation: {
+        dwell: 'dwell linear forwards',
+      },
     },
   },
   plugins: [